@@ -1,12 +1,75 @@
-use sdk_core::types::error::CoreError;
+use sdk_core::types::{
+	avail::transaction_validity::{InvalidTransaction, UnknownTransaction},
+	error::CoreError,
+};
+use std::fmt::Display;
+
+type JsonrpseeError = jsonrpsee_core::client::error::Error;
 
 #[derive(Debug)]
 pub enum ClientError {
-	Jsonrpsee(jsonrpsee_core::client::error::Error),
+	/// The node answered the request with an error.
+	Rpc(RpcError),
+	/// The request did not get an answer, e.g. the connection failed or timed out.
+	Transport(JsonrpseeError),
 	Core(CoreError),
 	CodecError(parity_scale_codec::Error),
 	SerdeJson(serde_json::Error),
 	FromHexError(hex::FromHexError),
+	/// A value that could not be fetched from the network was not provided.
+	MissingParameter(&'static str),
+}
+impl ClientError {
+	/// Returns the transaction pool error, if that is what the node answered with.
+	pub fn pool_error(&self) -> Option<&PoolError> {
+		match self {
+			ClientError::Rpc(RpcError::Pool(x)) => Some(x),
+			_ => None,
+		}
+	}
+
+	/// Returns true if sending the same request again later might succeed.
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			ClientError::Transport(e) => matches!(
+				e,
+				JsonrpseeError::Transport(_)
+					| JsonrpseeError::RestartNeeded(_)
+					| JsonrpseeError::RequestTimeout
+			),
+			ClientError::Rpc(RpcError::Pool(x)) => x.is_retryable(),
+			_ => false,
+		}
+	}
+
+	/// Returns true if the transaction was rejected because of its nonce.
+	pub fn is_nonce_error(&self) -> bool {
+		self.pool_error().is_some_and(|x| x.is_nonce_error())
+	}
+}
+impl Display for ClientError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ClientError::Rpc(e) => write!(f, "{e}"),
+			ClientError::Transport(e) => write!(f, "RPC transport error: {e}"),
+			ClientError::Core(e) => write!(f, "{e}"),
+			ClientError::CodecError(e) => write!(f, "Cannot decode response: {e}"),
+			ClientError::SerdeJson(e) => write!(f, "JSON error: {e}"),
+			ClientError::FromHexError(e) => write!(f, "Cannot parse hex string: {e}"),
+			ClientError::MissingParameter(x) => write!(f, "Missing parameter: {x}"),
+		}
+	}
+}
+impl std::error::Error for ClientError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			ClientError::Transport(e) => Some(e),
+			ClientError::Core(e) => Some(e),
+			ClientError::SerdeJson(e) => Some(e),
+			ClientError::FromHexError(e) => Some(e),
+			_ => None,
+		}
+	}
 }
 impl From<CoreError> for ClientError {
 	fn from(value: CoreError) -> Self {
@@ -18,9 +81,15 @@ impl From<parity_scale_codec::Error> for ClientError {
 		ClientError::CodecError(value)
 	}
 }
-impl From<jsonrpsee_core::client::error::Error> for ClientError {
-	fn from(value: jsonrpsee_core::client::error::Error) -> Self {
-		ClientError::Jsonrpsee(value)
+impl From<JsonrpseeError> for ClientError {
+	fn from(value: JsonrpseeError) -> Self {
+		match value {
+			JsonrpseeError::Call(x) => {
+				let data = x.data().map(|x| x.get().to_string());
+				ClientError::Rpc(RpcError::new(x.code(), x.message().to_string(), data))
+			},
+			x => ClientError::Transport(x),
+		}
 	}
 }
 impl From<hex::FromHexError> for ClientError {
@@ -28,3 +97,278 @@ impl From<hex::FromHexError> for ClientError {
 		ClientError::FromHexError(value)
 	}
 }
+impl From<serde_json::Error> for ClientError {
+	fn from(value: serde_json::Error) -> Self {
+		ClientError::SerdeJson(value)
+	}
+}
+
+/// An error object returned by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpcError {
+	/// Error codes 1010 to 1020, returned by `author_*` methods when the transaction pool
+	/// rejects a transaction.
+	Pool(PoolError),
+	Other {
+		code: i32,
+		message: String,
+		/// Raw JSON of the `data` field.
+		data: Option<String>,
+	},
+}
+impl RpcError {
+	pub fn new(code: i32, message: String, data: Option<String>) -> Self {
+		// `data` is usually a JSON string. Anything else is kept as is.
+		let data_str = data
+			.as_ref()
+			.map(|x| serde_json::from_str::<String>(x).unwrap_or_else(|_| x.clone()));
+
+		match PoolError::from_parts(code, &message, data_str.as_deref()) {
+			Some(x) => RpcError::Pool(x),
+			None => RpcError::Other {
+				code,
+				message,
+				data,
+			},
+		}
+	}
+}
+impl Display for RpcError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RpcError::Pool(e) => write!(f, "{e}"),
+			RpcError::Other {
+				code,
+				message,
+				data: Some(data),
+			} => write!(f, "RPC error {code}: {message} ({data})"),
+			RpcError::Other {
+				code,
+				message,
+				data: None,
+			} => write!(f, "RPC error {code}: {message}"),
+		}
+	}
+}
+
+/// Transaction pool errors, by the JSON-RPC error code used by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolError {
+	/// Code 1010: the transaction is invalid. `None` if the reason was not recognized.
+	InvalidTransaction(Option<InvalidTransaction>),
+	/// Code 1011: the validity could not be determined. `None` if the reason was not recognized.
+	UnknownTransaction(Option<UnknownTransaction>),
+	/// Code 1012: the transaction was recently rejected and is temporarily banned.
+	TemporarilyBanned,
+	/// Code 1013: the same transaction is already in the pool.
+	AlreadyImported,
+	/// Code 1014: another transaction with the same tags (e.g. same signer and nonce) and a
+	/// higher or equal priority is already in the pool.
+	TooLowPriority { old: u64, new: u64 },
+	/// Code 1015: the transaction depends on itself.
+	CycleDetected,
+	/// Code 1016: the transaction was dropped right away, e.g. because the pool is full.
+	ImmediatelyDropped,
+	/// Code 1017: the transaction cannot be propagated and the node does not author blocks.
+	Unactionable,
+	/// Code 1018: the transaction does not provide any tags, so the pool can't identify it.
+	NoTagsProvided,
+	/// Code 1019: the block the transaction was validated against is not known.
+	InvalidBlockId(String),
+	/// Code 1020: the pool does not accept transactions which are not valid yet.
+	RejectedFutureTransaction,
+}
+impl PoolError {
+	fn from_parts(code: i32, message: &str, data: Option<&str>) -> Option<Self> {
+		let error = match code {
+			1010 => Self::InvalidTransaction(data.and_then(invalid_transaction_from_str)),
+			1011 => Self::UnknownTransaction(data.and_then(unknown_transaction_from_str)),
+			1012 => Self::TemporarilyBanned,
+			1013 => Self::AlreadyImported,
+			1014 => {
+				// "Priority is too low: (old vs new)"
+				let (old, new) = message
+					.split_once('(')
+					.and_then(|(_, x)| x.trim_end_matches(')').split_once(" vs "))
+					.unwrap_or(("0", "0"));
+				Self::TooLowPriority {
+					old: old.trim().parse().unwrap_or(0),
+					new: new.trim().parse().unwrap_or(0),
+				}
+			},
+			1015 => Self::CycleDetected,
+			1016 => Self::ImmediatelyDropped,
+			1017 => Self::Unactionable,
+			1018 => Self::NoTagsProvided,
+			1019 => Self::InvalidBlockId(data.unwrap_or(message).to_string()),
+			1020 => Self::RejectedFutureTransaction,
+			_ => return None,
+		};
+
+		Some(error)
+	}
+
+	pub fn code(&self) -> i32 {
+		match self {
+			Self::InvalidTransaction(_) => 1010,
+			Self::UnknownTransaction(_) => 1011,
+			Self::TemporarilyBanned => 1012,
+			Self::AlreadyImported => 1013,
+			Self::TooLowPriority { .. } => 1014,
+			Self::CycleDetected => 1015,
+			Self::ImmediatelyDropped => 1016,
+			Self::Unactionable => 1017,
+			Self::NoTagsProvided => 1018,
+			Self::InvalidBlockId(_) => 1019,
+			Self::RejectedFutureTransaction => 1020,
+		}
+	}
+
+	pub fn is_retryable(&self) -> bool {
+		matches!(
+			self,
+			Self::TemporarilyBanned
+				| Self::ImmediatelyDropped
+				| Self::RejectedFutureTransaction
+				| Self::InvalidTransaction(Some(
+					InvalidTransaction::Future | InvalidTransaction::ExhaustsResources
+				))
+		)
+	}
+
+	pub fn is_nonce_error(&self) -> bool {
+		matches!(
+			self,
+			Self::TooLowPriority { .. }
+				| Self::RejectedFutureTransaction
+				| Self::InvalidTransaction(Some(
+					InvalidTransaction::Stale | InvalidTransaction::Future
+				))
+		)
+	}
+}
+impl Display for PoolError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let code = self.code();
+		match self {
+			Self::InvalidTransaction(Some(x)) => write!(f, "{code} Invalid Transaction: {x:?}"),
+			Self::InvalidTransaction(None) => write!(f, "{code} Invalid Transaction"),
+			Self::UnknownTransaction(Some(x)) => {
+				write!(f, "{code} Unknown Transaction Validity: {x:?}")
+			},
+			Self::UnknownTransaction(None) => write!(f, "{code} Unknown Transaction Validity"),
+			Self::TemporarilyBanned => write!(f, "{code} Transaction is temporarily banned"),
+			Self::AlreadyImported => write!(f, "{code} Transaction Already Imported"),
+			Self::TooLowPriority { old, new } => {
+				write!(f, "{code} Priority is too low: ({old} vs {new})")
+			},
+			Self::CycleDetected => write!(f, "{code} Cycle Detected"),
+			Self::ImmediatelyDropped => write!(f, "{code} Immediately Dropped"),
+			Self::Unactionable => write!(f, "{code} Unactionable"),
+			Self::NoTagsProvided => write!(f, "{code} No tags provided"),
+			Self::InvalidBlockId(x) => write!(f, "{code} Invalid block id: {x}"),
+			Self::RejectedFutureTransaction => write!(f, "{code} Rejecting future transaction"),
+		}
+	}
+}
+
+// Strings are the ones used by `sp_runtime::transaction_validity`.
+fn invalid_transaction_from_str(s: &str) -> Option<InvalidTransaction> {
+	let value = match s {
+		"Transaction call is not expected" => InvalidTransaction::Call,
+		"Inability to pay some fees (e.g. account balance too low)" => InvalidTransaction::Payment,
+		"Transaction will be valid in the future" => InvalidTransaction::Future,
+		"Transaction is outdated" => InvalidTransaction::Stale,
+		"Transaction has a bad signature" => InvalidTransaction::BadProof,
+		"Transaction has an ancient birth block" => InvalidTransaction::AncientBirthBlock,
+		"Transaction would exhaust the block limits" => InvalidTransaction::ExhaustsResources,
+		"A call was labelled as mandatory, but resulted in an Error." => {
+			InvalidTransaction::BadMandatory
+		},
+		"Transaction dispatch is mandatory; transactions must not be validated." => {
+			InvalidTransaction::MandatoryValidation
+		},
+		"Invalid signing address" => InvalidTransaction::BadSigner,
+		x => {
+			let custom = x.strip_prefix("Custom error: ")?;
+			InvalidTransaction::Custom(custom.parse().ok()?)
+		},
+	};
+
+	Some(value)
+}
+
+// The node sends the `{:?}` form of `UnknownTransaction`, e.g. "CannotLookup" or "Custom(3)".
+fn unknown_transaction_from_str(s: &str) -> Option<UnknownTransaction> {
+	let value = match s {
+		"CannotLookup" => UnknownTransaction::CannotLookup,
+		"NoUnsignedValidator" => UnknownTransaction::NoUnsignedValidator,
+		x => {
+			let custom = x.strip_prefix("Custom(")?.strip_suffix(')')?;
+			UnknownTransaction::Custom(custom.parse().ok()?)
+		},
+	};
+
+	Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// `code`, `message` and raw `data` as sent by the node.
+	fn pool_error(code: i32, message: &str, data: &str) -> Option<PoolError> {
+		match RpcError::new(code, message.to_string(), Some(data.to_string())) {
+			RpcError::Pool(x) => Some(x),
+			RpcError::Other { .. } => None,
+		}
+	}
+
+	#[test]
+	fn invalid_transaction() {
+		let error = pool_error(
+			1010,
+			"Invalid Transaction",
+			r#""Transaction has a bad signature""#,
+		);
+		let expected = PoolError::InvalidTransaction(Some(InvalidTransaction::BadProof));
+		assert_eq!(error, Some(expected));
+
+		let error = pool_error(1010, "Invalid Transaction", r#""Transaction is outdated""#);
+		assert!(error.unwrap().is_nonce_error());
+
+		let error = pool_error(1010, "Invalid Transaction", r#""Custom error: 5""#);
+		let expected = PoolError::InvalidTransaction(Some(InvalidTransaction::Custom(5)));
+		assert_eq!(error, Some(expected));
+	}
+
+	#[test]
+	fn unknown_transaction() {
+		let message = "Unknown Transaction Validity";
+
+		let error = pool_error(1011, message, r#""CannotLookup""#);
+		let expected = PoolError::UnknownTransaction(Some(UnknownTransaction::CannotLookup));
+		assert_eq!(error, Some(expected));
+
+		let error = pool_error(1011, message, r#""NoUnsignedValidator""#);
+		let expected = UnknownTransaction::NoUnsignedValidator;
+		assert_eq!(error, Some(PoolError::UnknownTransaction(Some(expected))));
+
+		let error = pool_error(1011, message, r#""Custom(3)""#);
+		let expected = PoolError::UnknownTransaction(Some(UnknownTransaction::Custom(3)));
+		assert_eq!(error, Some(expected));
+	}
+
+	#[test]
+	fn too_low_priority() {
+		let data = r#""The transaction has too low priority to replace another transaction already in the pool.""#;
+		let error = pool_error(1014, "Priority is too low: (10 vs 5)", data);
+		assert_eq!(error, Some(PoolError::TooLowPriority { old: 10, new: 5 }));
+		assert!(error.unwrap().is_nonce_error());
+	}
+
+	#[test]
+	fn other_codes_are_not_pool_errors() {
+		assert_eq!(pool_error(-32602, "Invalid params", "null"), None);
+	}
+}
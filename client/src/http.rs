@@ -1,32 +1,64 @@
 use super::params::{Extra, Mortality, Nonce};
-use crate::{error::ClientError, rpc};
+use crate::{
+	error::ClientError,
+	nonce::{self, NonceManager},
+	offline::{self, OfflineClient},
+	rpc,
+	transport::RpcTransport,
+};
 use jsonrpsee_http_client::HttpClient as JRPSHttpClient;
 use parity_scale_codec::Compact;
 use sdk_core::{
-	crypto::{AccountId, Signature},
+	crypto::{AccountId, Signer},
 	types::{
-		self, avail::RuntimeVersion, Additional, Call, Era, OpaqueTransaction,
-		UnsignedEncodedPayload, UnsignedPayload, H256,
+		self,
+		avail::{
+			transaction_payment::FeeDetails,
+			transaction_validity::{TransactionSource, TransactionValidity},
+			BlockNumber,
+		},
+		multi::{MultiAddress, MultiSignature},
+		Call, Era, OpaqueTransaction, PayloadEnvelope, SignatureEnvelope, UnsignedEncodedPayload,
+		H256,
 	},
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-#[derive(Debug, Clone)]
-pub struct Client {
-	pub client: Arc<JRPSHttpClient>,
+#[derive(Debug)]
+pub struct Client<T: RpcTransport = JRPSHttpClient> {
+	pub client: Arc<T>,
 	genesis_hash: H256,
+	nonce_manager: Arc<NonceManager>,
+}
+
+impl<T: RpcTransport> Clone for Client<T> {
+	fn clone(&self) -> Self {
+		Self {
+			client: self.client.clone(),
+			genesis_hash: self.genesis_hash,
+			nonce_manager: self.nonce_manager.clone(),
+		}
+	}
 }
 
 impl Client {
 	pub async fn new(endpoint: &str) -> Result<Self, ClientError> {
 		let client = JRPSHttpClient::builder().build(endpoint);
-		let client = client.map_err(|e| ClientError::Jsonrpsee(e))?;
+		let client = client.map_err(ClientError::from)?;
+
+		Self::with_transport(client).await
+	}
+}
 
-		let genesis_hash = rpc::chain_spec_v1_genesis_hash(&client).await?;
+impl<T: RpcTransport> Client<T> {
+	/// Creates a client that sends its requests through `transport`.
+	pub async fn with_transport(transport: T) -> Result<Self, ClientError> {
+		let genesis_hash = rpc::chain_spec_v1_genesis_hash(&transport).await?;
 
 		Ok(Self {
-			client: Arc::new(client),
+			client: Arc::new(transport),
 			genesis_hash,
+			nonce_manager: Arc::new(NonceManager::new()),
 		})
 	}
 
@@ -34,6 +66,21 @@ impl Client {
 		self.genesis_hash.clone()
 	}
 
+	/// Nonce manager used for [`Nonce::Managed`]. It is shared between clones of this client.
+	pub fn nonce_manager(&self) -> &NonceManager {
+		&self.nonce_manager
+	}
+
+	/// Fetches the current runtime version and returns an [`OfflineClient`] that can keep
+	/// building payloads without network access.
+	pub async fn offline_client(&self) -> Result<OfflineClient, ClientError> {
+		let runtime_version = rpc::state_get_runtime_version(&self.client).await?;
+		Ok(OfflineClient::from_runtime_version(
+			self.genesis_hash,
+			&runtime_version,
+		))
+	}
+
 	pub async fn build_payload(
 		&self,
 		call: Call,
@@ -44,8 +91,12 @@ impl Client {
 
 		let app_id = Compact(app_id.unwrap_or(0u32));
 		let tip = Compact(tip.unwrap_or(0u128));
-		let nonce = self.check_nonce(nonce, &account_id).await?;
 		let (mortality, fork_hash) = self.check_mortality(mortality).await?;
+		let runtime_version = rpc::state_get_runtime_version(&self.client).await?;
+		let offline = OfflineClient::from_runtime_version(self.genesis_hash, &runtime_version);
+		// Fetched last so that a managed nonce is not left reserved when one of the calls above
+		// fails.
+		let nonce = self.check_nonce(nonce, &account_id).await?;
 
 		let extra = types::Extra {
 			mortality,
@@ -54,36 +105,108 @@ impl Client {
 			app_id,
 		};
 
-		let RuntimeVersion {
-			spec_version,
-			transaction_version,
-			..
-		} = rpc::state_get_runtime_version(&self.client).await?;
+		Ok(offline.encode_payload(call, extra, fork_hash))
+	}
 
-		let additional = Additional::new(
-			spec_version,
-			transaction_version,
-			self.genesis_hash,
-			fork_hash,
-		);
+	/// Estimates the fee `account_id` would pay for `call`. The transaction is signed with an
+	/// empty sr25519 signature, so the estimate is one byte short for ecdsa accounts.
+	/// [`Nonce::Managed`] is only peeked at, no nonce is reserved.
+	pub async fn estimate_fee(
+		&self,
+		call: Call,
+		account_id: AccountId,
+		mut extra: Extra,
+	) -> Result<FeeDetails, ClientError> {
+		if let (Some(Nonce::Managed), ..) = extra.deconstruct() {
+			let nonce = self.nonce_manager.peek(&self.client, &account_id).await?;
+			extra = extra.nonce(Nonce::Custom(nonce));
+		}
 
-		Ok(UnsignedPayload::new(call, extra, additional).encode())
+		let payload = self.build_payload(call, account_id, extra).await?;
+
+		let signature = MultiSignature::Sr25519([0u8; 64]);
+		let transaction = self.build_transaction(&payload, account_id, signature);
+
+		rpc::transaction_payment_api_query_fee_details(&self.client, &transaction, None).await
 	}
 
 	pub fn build_transaction(
 		&self,
 		payload: &UnsignedEncodedPayload,
-		account_id: AccountId,
-		signature: Signature,
+		address: impl Into<MultiAddress>,
+		signature: impl Into<MultiSignature>,
 	) -> OpaqueTransaction {
-		OpaqueTransaction::new(&payload.extra, &payload.call, account_id, signature)
+		offline::build_transaction(payload, address, signature)
+	}
+
+	/// Joins an envelope with the signature produced for it. Fails if the envelope was built
+	/// for a different chain or if the signature belongs to another payload.
+	pub fn build_transaction_from_envelope(
+		&self,
+		payload: &PayloadEnvelope,
+		signature: &SignatureEnvelope,
+	) -> Result<OpaqueTransaction, ClientError> {
+		Ok(payload.into_transaction(signature, self.genesis_hash)?)
+	}
+
+	/// Signs `payload` with `signer`. If signing fails, a nonce reserved for the payload by
+	/// [`Nonce::Managed`] is released.
+	pub async fn sign_transaction<S: Signer + ?Sized>(
+		&self,
+		payload: &UnsignedEncodedPayload,
+		signer: &S,
+	) -> Result<OpaqueTransaction, ClientError> {
+		let result = offline::sign_transaction(payload, signer);
+		if result.is_err() {
+			if let Ok(x) = payload.decode() {
+				let account_id = signer.account_id();
+				self.nonce_manager
+					.release(&account_id, x.extra.nonce.0)
+					.await;
+			}
+		}
+
+		result
+	}
+
+	/// Checks `transaction` against the best block the same way the transaction pool would,
+	/// without submitting it.
+	pub async fn validate_transaction(
+		&self,
+		transaction: &OpaqueTransaction,
+	) -> Result<TransactionValidity, ClientError> {
+		let block_hash = rpc::fetch_best_block_hash(&self.client).await?;
+		rpc::tagged_transaction_queue_validate_transaction(
+			&self.client,
+			TransactionSource::External,
+			transaction,
+			block_hash,
+		)
+		.await
 	}
 
 	pub async fn submit_transaction(
 		&self,
 		transaction: OpaqueTransaction,
 	) -> Result<H256, ClientError> {
-		rpc::author_submit_extrinsic(&self.client, transaction).await
+		let signer = nonce::signer_and_nonce(&transaction);
+		let result = rpc::author_submit_extrinsic(&self.client, transaction).await;
+		self.report_submission(signer, result.as_ref().err()).await;
+
+		result
+	}
+
+	/// Like [`Client::submit_transaction`], but returns a [`SubmittedTransaction`] that can be
+	/// used to wait for the transaction to be included.
+	pub async fn submit_and_track_transaction(
+		&self,
+		transaction: OpaqueTransaction,
+	) -> Result<SubmittedTransaction, ClientError> {
+		let block_number = rpc::fetch_block_header(&self.client, None).await?.number;
+		let submitted = SubmittedTransaction::from_transaction(&transaction, block_number)?;
+		self.submit_transaction(transaction).await?;
+
+		Ok(submitted)
 	}
 
 	async fn check_nonce(
@@ -103,12 +226,26 @@ impl Client {
 				let block_hash = rpc::fetch_finalized_block_hash(&self.client).await?;
 				rpc::account_nonce_api_account_nonce(&self.client, &account_id, block_hash).await?
 			},
+			Some(Nonce::Managed) => self.nonce_manager.next(&self.client, account_id).await?,
 			Some(Nonce::Custom(n)) => n,
 		};
 
 		Ok(Compact(nonce))
 	}
 
+	// Lets the nonce manager know how the submission of a transaction by `signer` went.
+	pub(crate) async fn report_submission(
+		&self,
+		signer: Option<(AccountId, u32)>,
+		error: Option<&ClientError>,
+	) {
+		if let Some((account_id, nonce)) = signer {
+			self.nonce_manager
+				.submitted(&account_id, nonce, error)
+				.await;
+		}
+	}
+
 	async fn check_mortality(
 		&self,
 		mortality: Option<Mortality>,
@@ -124,6 +261,7 @@ impl Client {
 				Mortality::Custom((period, best_number, block_hash)) => {
 					(Era::mortal(period, best_number as u64), block_hash)
 				},
+				Mortality::Immortal => (Era::Immortal, self.genesis_hash),
 			},
 			None => {
 				let hash = rpc::fetch_best_block_hash(&self.client).await?;
@@ -137,12 +275,262 @@ impl Client {
 	}
 }
 
+/// A transaction that was sent to the node, tracked by polling blocks over plain RPC calls.
+#[derive(Debug, Clone)]
 pub struct SubmittedTransaction {
 	pub tx_hash: H256,
+	/// Mortality of the transaction. Used to tell when it can no longer be included.
+	pub mortality: Era,
+	/// Best block number at the time of submission. Blocks before it are not searched.
+	pub block_number: BlockNumber,
 }
 
 impl SubmittedTransaction {
-	pub fn new(tx_hash: H256) -> Self {
-		Self { tx_hash }
+	pub fn new(tx_hash: H256, mortality: Era, block_number: BlockNumber) -> Self {
+		Self {
+			tx_hash,
+			mortality,
+			block_number,
+		}
+	}
+
+	/// Reads the hash and mortality from `transaction`. Unsigned transactions are immortal.
+	pub fn from_transaction(
+		transaction: &OpaqueTransaction,
+		block_number: BlockNumber,
+	) -> Result<Self, ClientError> {
+		let mortality = match transaction.decoded()?.signature {
+			Some(x) => x.extra.mortality,
+			None => Era::Immortal,
+		};
+
+		Ok(Self::new(transaction.get_hash(), mortality, block_number))
+	}
+
+	/// Polls `client` until the transaction shows up in a best or finalized block, depending on
+	/// `target`. Returns [`TransactionState::Expired`] once every block the transaction could
+	/// have been included in was searched without finding it.
+	///
+	/// Best blocks are only searched once, so an inclusion that is later retracted by a re-org
+	/// is still reported.
+	pub async fn wait_for<T: RpcTransport>(
+		&self,
+		client: &Client<T>,
+		target: WaitFor,
+	) -> Result<TransactionState, ClientError> {
+		let death = self.mortality.death(self.block_number as u64);
+		let mut next_block_number = self.block_number;
+
+		loop {
+			let head_hash = match target {
+				WaitFor::InBlock => rpc::fetch_best_block_hash(&client.client).await?,
+				WaitFor::Finalized => rpc::fetch_finalized_block_hash(&client.client).await?,
+			};
+			let head_number = rpc::fetch_block_header(&client.client, Some(head_hash))
+				.await?
+				.number;
+
+			while next_block_number <= head_number {
+				if next_block_number as u64 >= death {
+					return Ok(TransactionState::Expired);
+				}
+
+				if let Some(location) = self.find_in_block(client, next_block_number).await? {
+					return Ok(TransactionState::Included(location));
+				}
+				next_block_number += 1;
+			}
+
+			tokio::time::sleep(POLL_INTERVAL).await;
+		}
+	}
+
+	async fn find_in_block<T: RpcTransport>(
+		&self,
+		client: &Client<T>,
+		block_number: BlockNumber,
+	) -> Result<Option<TransactionLocation>, ClientError> {
+		let Some(block_hash) = rpc::fetch_block_hash(&client.client, block_number).await? else {
+			return Ok(None);
+		};
+		let block = rpc::fetch_block(&client.client, Some(block_hash))
+			.await?
+			.block;
+
+		for (tx_index, extrinsic) in block.extrinsics.iter().enumerate() {
+			let transaction = OpaqueTransaction::from_hex_string(extrinsic)?;
+			if transaction.get_hash() == self.tx_hash {
+				return Ok(Some(TransactionLocation {
+					block_hash,
+					block_number,
+					tx_index: tx_index as u32,
+				}));
+			}
+		}
+
+		Ok(None)
+	}
+}
+
+/// How long [`SubmittedTransaction::wait_for`] sleeps before checking for new blocks.
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitFor {
+	/// Wait until the transaction is included in a block of the best chain.
+	InBlock,
+	/// Wait until the transaction is included in a finalized block.
+	Finalized,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionState {
+	Included(TransactionLocation),
+	/// The mortality of the transaction has passed and it was not included.
+	Expired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionLocation {
+	pub block_hash: H256,
+	pub block_number: BlockNumber,
+	/// Index of the transaction inside the block.
+	pub tx_index: u32,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{offline::OfflineClient, transport::MockTransport};
+	use sdk_core::{
+		crypto::{ed25519, Keypair, SecretUri},
+		types::{avail::DataAvailabilityCalls, multi::SignatureKind},
+	};
+	use serde_json::json;
+	use std::str::FromStr;
+
+	const GENESIS_HASH: H256 = H256([1u8; 32]);
+	const BLOCK_HASH: H256 = H256([2u8; 32]);
+
+	fn transaction(nonce: u32, mortality: Mortality) -> OpaqueTransaction {
+		let client = OfflineClient::new(GENESIS_HASH, 39, 1);
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra::new()
+			.nonce(Nonce::Custom(nonce))
+			.mortality(mortality);
+		let payload = client.build_payload(call, extra).unwrap();
+		let signer = Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap();
+
+		client.sign_transaction(&payload, &signer).unwrap()
+	}
+
+	// Every block up to `head` has the same hash and contains `extrinsics`.
+	fn mock(head: BlockNumber, extrinsics: &[OpaqueTransaction]) -> MockTransport {
+		let hash = GENESIS_HASH.to_hex_string();
+		let header = json!({
+			"parentHash": hash,
+			"number": std::format!("0x{head:x}"),
+			"stateRoot": hash,
+			"extrinsicsRoot": hash,
+			"digest": { "logs": [] },
+			"extension": { "V3": {
+				"appLookup": { "size": 0, "index": [] },
+				"commitment": { "rows": 0, "cols": 0, "commitment": [], "dataRoot": hash },
+			}},
+		});
+		let extrinsics: Vec<String> = extrinsics.iter().map(|x| x.to_hex_string()).collect();
+		let block = json!({
+			"block": { "header": header, "extrinsics": extrinsics },
+			"justifications": null,
+		});
+
+		MockTransport::new()
+			.with("chainSpec_v1_genesisHash", json!(hash))
+			.with("chain_getBlockHash", json!(BLOCK_HASH.to_hex_string()))
+			.with("chain_getFinalizedHead", json!(BLOCK_HASH.to_hex_string()))
+			.with("chain_getHeader", header)
+			.with("chain_getBlock", block)
+	}
+
+	#[tokio::test]
+	async fn wait_for_included() {
+		let other = transaction(1, Mortality::Immortal);
+		let transaction = transaction(2, Mortality::Custom((32, 100, BLOCK_HASH)));
+		let client = Client::with_transport(mock(105, &[other, transaction.clone()]))
+			.await
+			.unwrap();
+
+		let submitted = SubmittedTransaction::from_transaction(&transaction, 105).unwrap();
+		assert_eq!(submitted.mortality, Era::mortal(32, 100));
+
+		let expected = TransactionState::Included(TransactionLocation {
+			block_hash: BLOCK_HASH,
+			block_number: 105,
+			tx_index: 1,
+		});
+		for target in [WaitFor::InBlock, WaitFor::Finalized] {
+			let state = submitted.wait_for(&client, target).await.unwrap();
+			assert_eq!(state, expected);
+		}
+	}
+
+	#[tokio::test]
+	async fn wait_for_expired() {
+		let other = transaction(1, Mortality::Immortal);
+		let transaction = transaction(2, Mortality::Custom((4, 100, BLOCK_HASH)));
+		// The era covers blocks 100 to 103, the head is already past it.
+		let client = Client::with_transport(mock(110, &[other])).await.unwrap();
+
+		let submitted = SubmittedTransaction::from_transaction(&transaction, 100).unwrap();
+		assert_eq!(submitted.mortality.death(100), 104);
+
+		let state = submitted.wait_for(&client, WaitFor::InBlock).await.unwrap();
+		assert_eq!(state, TransactionState::Expired);
+	}
+
+	/// Announces sr25519 but signs with ed25519.
+	struct Mislabeled(ed25519::Keypair);
+	impl Signer for Mislabeled {
+		fn account_id(&self) -> AccountId {
+			self.0.account_id()
+		}
+
+		fn signature_kind(&self) -> SignatureKind {
+			SignatureKind::Sr25519
+		}
+
+		fn sign(&self, message: &[u8]) -> MultiSignature {
+			Signer::sign(&self.0, message)
+		}
+	}
+
+	#[tokio::test]
+	async fn failed_signing_releases_managed_nonce() {
+		let runtime_version = json!({
+			"specName": "avail",
+			"implName": "avail",
+			"authoringVersion": 1,
+			"specVersion": 39,
+			"implVersion": 0,
+			"apis": [],
+			"transactionVersion": 1,
+			"stateVersion": 1,
+		});
+		let transport = mock(100, &[])
+			.with("state_getRuntimeVersion", runtime_version)
+			.with("system_accountNextIndex", json!(3));
+		let client = Client::with_transport(transport).await.unwrap();
+		let signer = Mislabeled(
+			ed25519::Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap(),
+		);
+		let account_id = signer.account_id();
+
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra::new().nonce(Nonce::Managed);
+		let payload = client.build_payload(call, account_id, extra).await.unwrap();
+		assert!(client.sign_transaction(&payload, &signer).await.is_err());
+
+		let manager = client.nonce_manager();
+		assert_eq!(manager.next(&client.client, &account_id).await.unwrap(), 3);
 	}
 }
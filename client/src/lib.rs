@@ -1,6 +1,11 @@
 pub mod error;
 pub mod http;
+pub mod nonce;
+pub mod offline;
 pub mod params;
 pub mod rpc;
+pub mod transport;
+#[cfg(feature = "ws")]
+pub mod ws;
 
 pub use sdk_core as core;
@@ -0,0 +1,295 @@
+use crate::{
+	error::{ClientError, PoolError, RpcError},
+	rpc,
+	transport::RpcTransport,
+};
+use sdk_core::{
+	crypto::AccountId,
+	types::{multi::MultiAddress, OpaqueTransaction},
+};
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// How long the local view of an account is trusted before it is compared with the node again.
+const SYNC_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Hands out nonces for [`Nonce::Managed`](crate::params::Nonce::Managed) so that transactions
+/// built in parallel for the same account don't end up with the same nonce.
+///
+/// The first nonce of an account comes from `system_accountNextIndex`. After that nonces are
+/// counted up locally. Every 20 seconds, and after a submission fails because of its nonce, the
+/// count is compared with the node again to find nonces that were handed out but never made it
+/// into the pool. A nonce the node is still waiting for is handed out again once it has been in
+/// flight for longer than that, e.g. because its payload was never submitted.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+	// Every account has its own lock, so a slow node call only blocks that account. `None` until
+	// the first nonce was fetched.
+	accounts: Mutex<HashMap<AccountId, Arc<Mutex<Option<AccountNonces>>>>>,
+}
+
+#[derive(Debug)]
+struct AccountNonces {
+	/// Lowest nonce that was never handed out.
+	next: u32,
+	/// Nonces that were handed out and whose submission has not finished yet, with the time
+	/// they were handed out.
+	in_flight: BTreeMap<u32, Instant>,
+	/// Nonces below `next` that are free again, e.g. because their transaction was dropped.
+	gaps: BTreeSet<u32>,
+	/// `None` if the account has to be synced before handing out the next nonce.
+	last_sync: Option<Instant>,
+}
+impl AccountNonces {
+	fn new(next: u32) -> Self {
+		Self {
+			next,
+			in_flight: BTreeMap::new(),
+			gaps: BTreeSet::new(),
+			last_sync: Some(Instant::now()),
+		}
+	}
+
+	fn needs_sync(&self) -> bool {
+		self.last_sync.is_none_or(|x| x.elapsed() >= SYNC_INTERVAL)
+	}
+
+	// `node_next` counts the nonces used on chain and by ready transactions in the pool. If it
+	// is below `next` and not in flight, the transaction with that nonce is gone. If it has been
+	// in flight for a whole sync interval, it was most likely never submitted. Nonces below
+	// `node_next` are used and no longer tracked.
+	fn sync(&mut self, node_next: u32) {
+		if node_next >= self.next {
+			self.next = node_next;
+		} else {
+			let stale = self
+				.in_flight
+				.get(&node_next)
+				.is_none_or(|x| x.elapsed() >= SYNC_INTERVAL);
+			if stale {
+				self.in_flight.remove(&node_next);
+				self.gaps.insert(node_next);
+			}
+		}
+		self.in_flight.retain(|x, _| *x >= node_next);
+		self.gaps.retain(|x| *x >= node_next);
+		self.last_sync = Some(Instant::now());
+	}
+}
+
+impl NonceManager {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the nonce to use for the next transaction of `account_id`. Gaps are filled first.
+	///
+	/// The nonce stays reserved until it is passed to [`NonceManager::submitted`] or
+	/// [`NonceManager::release`].
+	pub async fn next<T: RpcTransport + ?Sized>(
+		&self,
+		client: &T,
+		account_id: &AccountId,
+	) -> Result<u32, ClientError> {
+		let entry = self.entry(account_id).await;
+		let mut account = entry.lock().await;
+		let account = sync_account(&mut account, client, account_id).await?;
+
+		let nonce = match account.gaps.pop_first() {
+			Some(x) => x,
+			None => {
+				account.next += 1;
+				account.next - 1
+			},
+		};
+		account.in_flight.insert(nonce, Instant::now());
+
+		Ok(nonce)
+	}
+
+	/// Returns the nonce [`NonceManager::next`] would return, without reserving it.
+	pub async fn peek<T: RpcTransport + ?Sized>(
+		&self,
+		client: &T,
+		account_id: &AccountId,
+	) -> Result<u32, ClientError> {
+		let entry = self.entry(account_id).await;
+		let mut account = entry.lock().await;
+		let account = sync_account(&mut account, client, account_id).await?;
+
+		Ok(account.gaps.first().copied().unwrap_or(account.next))
+	}
+
+	/// Frees a nonce returned by [`NonceManager::next`] whose transaction will not be submitted.
+	pub async fn release(&self, account_id: &AccountId, nonce: u32) {
+		let Some(entry) = self.accounts.lock().await.get(account_id).cloned() else {
+			return;
+		};
+		let mut account = entry.lock().await;
+		let Some(account) = account.as_mut() else {
+			return;
+		};
+		if account.in_flight.remove(&nonce).is_some() {
+			account.gaps.insert(nonce);
+		}
+	}
+
+	/// Records the outcome of submitting a transaction with a managed `nonce`. Nonces that were
+	/// not handed out by this manager are ignored.
+	///
+	/// If the node rejected the transaction, the nonce is handed out again. If the transaction
+	/// might be in the pool anyway, e.g. after a timeout or because it was already imported, the
+	/// nonce stays reserved and the next call to [`NonceManager::next`] resyncs the account with
+	/// the node, which frees it if the node never got it.
+	pub async fn submitted(&self, account_id: &AccountId, nonce: u32, error: Option<&ClientError>) {
+		let Some(entry) = self.accounts.lock().await.get(account_id).cloned() else {
+			return;
+		};
+		let mut account = entry.lock().await;
+		let Some(account) = account.as_mut() else {
+			return;
+		};
+
+		let Some(error) = error else {
+			account.in_flight.remove(&nonce);
+			return;
+		};
+		if !is_rejection(error) {
+			account.last_sync = None;
+			return;
+		}
+		if account.in_flight.remove(&nonce).is_some() {
+			account.gaps.insert(nonce);
+		}
+		if error.is_nonce_error() {
+			account.last_sync = None;
+		}
+	}
+
+	/// Forgets everything about `account_id`. The next nonce is fetched from the node again.
+	pub async fn reset(&self, account_id: &AccountId) {
+		self.accounts.lock().await.remove(account_id);
+	}
+
+	async fn entry(&self, account_id: &AccountId) -> Arc<Mutex<Option<AccountNonces>>> {
+		let mut accounts = self.accounts.lock().await;
+		accounts.entry(*account_id).or_default().clone()
+	}
+}
+
+// Fetches the first nonce of the account, or syncs it with the node if it is due.
+async fn sync_account<'a, T: RpcTransport + ?Sized>(
+	account: &'a mut Option<AccountNonces>,
+	client: &T,
+	account_id: &AccountId,
+) -> Result<&'a mut AccountNonces, ClientError> {
+	match account {
+		Some(x) if !x.needs_sync() => {},
+		Some(x) => x.sync(rpc::system_account_next_index(client, account_id).await?),
+		None => {
+			let node_next = rpc::system_account_next_index(client, account_id).await?;
+			*account = Some(AccountNonces::new(node_next));
+		},
+	}
+
+	Ok(account.as_mut().expect("account is initialized above; qed"))
+}
+
+// True if the node answered and did not take the transaction. Errors where it might still be
+// in the pool, like timeouts or an already imported transaction, return false.
+fn is_rejection(error: &ClientError) -> bool {
+	match error {
+		ClientError::Rpc(RpcError::Pool(x)) => !matches!(
+			x,
+			PoolError::AlreadyImported | PoolError::TooLowPriority { .. }
+		),
+		ClientError::Rpc(RpcError::Other { .. }) => true,
+		_ => false,
+	}
+}
+
+/// Returns the signer and nonce of `transaction`, if it is signed by an account id.
+pub(crate) fn signer_and_nonce(transaction: &OpaqueTransaction) -> Option<(AccountId, u32)> {
+	let signature = transaction.decoded().ok()?.signature?;
+	match signature.address {
+		MultiAddress::Id(account_id) => Some((account_id, signature.extra.nonce.0)),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::transport::MockTransport;
+	use serde_json::json;
+
+	#[test]
+	fn sync_frees_stale_in_flight_nonce() {
+		let mut account = AccountNonces::new(5);
+		account.next = 7;
+		let handed_out = Instant::now() - SYNC_INTERVAL;
+		account.in_flight.insert(5, handed_out);
+		account.in_flight.insert(6, Instant::now());
+
+		account.sync(5);
+		assert_eq!(account.gaps, BTreeSet::from([5]));
+		assert!(!account.in_flight.contains_key(&5));
+
+		account.sync(6);
+		assert!(account.gaps.is_empty());
+		assert!(account.in_flight.contains_key(&6));
+	}
+
+	#[tokio::test]
+	async fn peek_and_release() {
+		let client = MockTransport::new().with("system_accountNextIndex", json!(3));
+		let manager = NonceManager::new();
+		let account_id = AccountId([1u8; 32]);
+
+		assert_eq!(manager.peek(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.peek(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 4);
+
+		manager.release(&account_id, 3).await;
+		assert_eq!(manager.peek(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 5);
+	}
+
+	#[tokio::test]
+	async fn submitted_keeps_nonces_that_may_be_in_the_pool() {
+		let client = MockTransport::new().with("system_accountNextIndex", json!(3));
+		let manager = NonceManager::new();
+		let account_id = AccountId([1u8; 32]);
+
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 3);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 4);
+
+		let error = ClientError::Rpc(RpcError::Pool(PoolError::AlreadyImported));
+		manager.submitted(&account_id, 3, Some(&error)).await;
+		let error = ClientError::Rpc(RpcError::Pool(PoolError::InvalidTransaction(None)));
+		manager.submitted(&account_id, 4, Some(&error)).await;
+
+		// 3 stays reserved, so only 4 is handed out again. The node still counts 3 as next, but
+		// it was only just handed out.
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 4);
+		assert_eq!(manager.next(&client, &account_id).await.unwrap(), 5);
+	}
+
+	#[tokio::test]
+	async fn slow_account_does_not_block_others() {
+		let client = MockTransport::new().with("system_accountNextIndex", json!(3));
+		let manager = NonceManager::new();
+		let (alice, bob) = (AccountId([1u8; 32]), AccountId([2u8; 32]));
+
+		let entry = manager.entry(&alice).await;
+		let _locked = entry.lock().await;
+		assert_eq!(manager.next(&client, &bob).await.unwrap(), 3);
+		manager.submitted(&bob, 3, None).await;
+	}
+}
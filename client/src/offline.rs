@@ -0,0 +1,140 @@
+use super::params::{Extra, Mortality, Nonce};
+use crate::error::ClientError;
+use parity_scale_codec::Compact;
+use sdk_core::{
+	crypto::Signer,
+	types::{
+		self,
+		avail::RuntimeVersion,
+		multi::{MultiAddress, MultiSignature},
+		Additional, Call, Era, OpaqueTransaction, UnsignedEncodedPayload, UnsignedPayload, H256,
+	},
+};
+
+/// Builds payloads and transactions without touching the network.
+///
+/// Everything [`Client`](crate::http::Client) would normally fetch has to be provided upfront:
+/// the genesis hash and runtime versions when constructing it, and the nonce and mortality
+/// checkpoint via [`Nonce::Custom`] and [`Mortality::Custom`] when building a payload.
+/// [`Mortality::Immortal`] needs no checkpoint.
+#[derive(Debug, Clone, Copy)]
+pub struct OfflineClient {
+	genesis_hash: H256,
+	spec_version: u32,
+	transaction_version: u32,
+}
+
+impl OfflineClient {
+	pub fn new(genesis_hash: H256, spec_version: u32, transaction_version: u32) -> Self {
+		Self {
+			genesis_hash,
+			spec_version,
+			transaction_version,
+		}
+	}
+
+	pub fn from_runtime_version(genesis_hash: H256, runtime_version: &RuntimeVersion) -> Self {
+		Self::new(
+			genesis_hash,
+			runtime_version.spec_version,
+			runtime_version.transaction_version,
+		)
+	}
+
+	pub fn genesis_hash(&self) -> H256 {
+		self.genesis_hash
+	}
+
+	pub fn spec_version(&self) -> u32 {
+		self.spec_version
+	}
+
+	pub fn transaction_version(&self) -> u32 {
+		self.transaction_version
+	}
+
+	/// Builds the payload from the given call and extra. Fails with
+	/// [`ClientError::MissingParameter`] unless the nonce is [`Nonce::Custom`] and the
+	/// mortality is [`Mortality::Custom`] or [`Mortality::Immortal`].
+	pub fn build_payload(
+		&self,
+		call: Call,
+		extra: Extra,
+	) -> Result<UnsignedEncodedPayload, ClientError> {
+		let (nonce, mortality, tip, app_id) = extra.deconstruct();
+
+		let Some(Nonce::Custom(nonce)) = nonce else {
+			return Err(ClientError::MissingParameter("Nonce::Custom"));
+		};
+		let (mortality, fork_hash) = match mortality {
+			Some(Mortality::Custom((period, best_number, block_hash))) => {
+				(Era::mortal(period, best_number as u64), block_hash)
+			},
+			Some(Mortality::Immortal) => (Era::Immortal, self.genesis_hash),
+			_ => return Err(ClientError::MissingParameter("Mortality::Custom")),
+		};
+
+		let extra = types::Extra {
+			mortality,
+			nonce: Compact(nonce),
+			tip: Compact(tip.unwrap_or(0u128)),
+			app_id: Compact(app_id.unwrap_or(0u32)),
+		};
+
+		Ok(self.encode_payload(call, extra, fork_hash))
+	}
+
+	/// Joins an already resolved extra with the runtime versions of this client.
+	pub(crate) fn encode_payload(
+		&self,
+		call: Call,
+		extra: types::Extra,
+		fork_hash: H256,
+	) -> UnsignedEncodedPayload {
+		let additional = Additional::new(
+			self.spec_version,
+			self.transaction_version,
+			self.genesis_hash,
+			fork_hash,
+		);
+
+		UnsignedPayload::new(call, extra, additional).encode()
+	}
+
+	pub fn build_transaction(
+		&self,
+		payload: &UnsignedEncodedPayload,
+		address: impl Into<MultiAddress>,
+		signature: impl Into<MultiSignature>,
+	) -> OpaqueTransaction {
+		build_transaction(payload, address, signature)
+	}
+
+	pub fn sign_transaction<S: Signer + ?Sized>(
+		&self,
+		payload: &UnsignedEncodedPayload,
+		signer: &S,
+	) -> Result<OpaqueTransaction, ClientError> {
+		sign_transaction(payload, signer)
+	}
+}
+
+pub(crate) fn build_transaction(
+	payload: &UnsignedEncodedPayload,
+	address: impl Into<MultiAddress>,
+	signature: impl Into<MultiSignature>,
+) -> OpaqueTransaction {
+	OpaqueTransaction::new(
+		&payload.extra,
+		&payload.call,
+		address.into(),
+		signature.into(),
+	)
+}
+
+pub(crate) fn sign_transaction<S: Signer + ?Sized>(
+	payload: &UnsignedEncodedPayload,
+	signer: &S,
+) -> Result<OpaqueTransaction, ClientError> {
+	Ok(OpaqueTransaction::from_signer(payload, signer)?)
+}
@@ -54,6 +54,13 @@ pub enum Nonce {
 	BestBlock,
 	FinalizedBlock,
 	BestBlockAndTxPool,
+	/// Taken from the [`NonceManager`](crate::nonce::NonceManager) of the client.
+	///
+	/// The nonce stays reserved until the transaction is submitted through the client. If the
+	/// payload is dropped instead, the nonce has to be passed to
+	/// [`NonceManager::release`](crate::nonce::NonceManager::release). Otherwise later
+	/// transactions of the account wait behind it until it is handed out again after 20 seconds.
+	Managed,
 	Custom(u32),
 }
 
@@ -61,4 +68,6 @@ pub enum Nonce {
 pub enum Mortality {
 	Period(avail::Period),
 	Custom((avail::Period, avail::BlockNumber, H256)),
+	/// Valid forever. The genesis hash is used as the checkpoint.
+	Immortal,
 }
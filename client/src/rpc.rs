@@ -1,18 +1,21 @@
-use crate::error::ClientError;
-use jsonrpsee_core::{client::ClientT, traits::ToRpcParams, JsonRawValue as RawValue};
-use jsonrpsee_http_client::HttpClient as JRPSHttpClient;
+use crate::{error::ClientError, transport::RpcTransport};
+use jsonrpsee_core::{traits::ToRpcParams, JsonRawValue as RawValue};
+use parity_scale_codec::{Decode, Encode};
 use sdk_core::{
 	crypto::{AccountId, Ss58Codec},
 	types::{
 		avail::{
 			block::SignedBlock,
+			dispatch::ApplyExtrinsicResult,
 			kate::{BlockLength, Cell, GDataProof, GRow, ProofResponse},
+			transaction_payment::{FeeDetails, RuntimeDispatchInfo},
+			transaction_validity::{TransactionSource, TransactionValidity},
 			BlockHeader, RuntimeVersion,
 		},
 		OpaqueTransaction, H256,
 	},
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 #[derive(Debug, Clone, Default)]
 pub struct RpcParams(Vec<u8>);
@@ -52,78 +55,163 @@ impl RpcParams {
 	}
 }
 
-pub async fn system_account_next_index(
-	client: &JRPSHttpClient,
+/// Sends a request through `client` and deserializes the result.
+pub async fn request<R: DeserializeOwned, T: RpcTransport + ?Sized>(
+	client: &T,
+	method: &str,
+	params: RpcParams,
+) -> Result<R, ClientError> {
+	let value = client.request_raw(method, params).await?;
+	serde_json::from_value(value).map_err(ClientError::from)
+}
+
+pub async fn system_account_next_index<T: RpcTransport + ?Sized>(
+	client: &T,
 	account_id: &AccountId,
 ) -> Result<u32, ClientError> {
 	let mut params = RpcParams::new();
 	params.push(account_id.to_ss58check())?;
 
-	let value: Result<u32, _> = client
-		.request::<u32, _>("system_accountNextIndex", params)
-		.await;
-
-	value.map_err(|e| ClientError::Jsonrpsee(e))
+	request(client, "system_accountNextIndex", params).await
 }
 
-pub async fn account_nonce_api_account_nonce(
-	client: &JRPSHttpClient,
+pub async fn account_nonce_api_account_nonce<T: RpcTransport + ?Sized>(
+	client: &T,
 	account_id: &AccountId,
 	block_hash: H256,
 ) -> Result<u32, ClientError> {
-	use parity_scale_codec::Decode;
-
 	let mut params = RpcParams::new();
 	params.push("AccountNonceApi_account_nonce")?;
 	params.push(account_id.to_hex_string())?;
 	params.push(Some(block_hash.to_hex_string()))?;
 
-	let encoded_value: Result<String, _> = client.request::<String, _>("state_call", params).await;
-	let encoded_value: String = encoded_value.map_err(ClientError::from)?;
-	let encoded_value = hex::decode(&encoded_value[2..]).map_err(ClientError::from)?;
+	let encoded_value: String = request(client, "state_call", params).await?;
+	let encoded_value =
+		hex::decode(encoded_value.trim_start_matches("0x")).map_err(ClientError::from)?;
 
 	u32::decode(&mut encoded_value.as_ref()).map_err(ClientError::from)
 }
 
-pub async fn fetch_best_block_hash(client: &JRPSHttpClient) -> Result<H256, ClientError> {
-	let value: Result<String, _> = client
-		.request::<_, _>("chain_getBlockHash", RpcParams::new())
-		.await;
-	let value: String = value.map_err(ClientError::from)?;
+pub async fn transaction_payment_api_query_info<T: RpcTransport + ?Sized>(
+	client: &T,
+	extrinsic: &OpaqueTransaction,
+	hash: Option<H256>,
+) -> Result<RuntimeDispatchInfo, ClientError> {
+	let encoded_value =
+		transaction_payment_api_call(client, "TransactionPaymentApi_query_info", extrinsic, hash)
+			.await?;
+
+	RuntimeDispatchInfo::decode(&mut encoded_value.as_ref()).map_err(ClientError::from)
+}
+
+pub async fn transaction_payment_api_query_fee_details<T: RpcTransport + ?Sized>(
+	client: &T,
+	extrinsic: &OpaqueTransaction,
+	hash: Option<H256>,
+) -> Result<FeeDetails, ClientError> {
+	let encoded_value = transaction_payment_api_call(
+		client,
+		"TransactionPaymentApi_query_fee_details",
+		extrinsic,
+		hash,
+	)
+	.await?;
+
+	FeeDetails::decode(&mut encoded_value.as_ref()).map_err(ClientError::from)
+}
+
+// Both runtime apis take the extrinsic followed by its encoded length.
+async fn transaction_payment_api_call<T: RpcTransport + ?Sized>(
+	client: &T,
+	method: &str,
+	extrinsic: &OpaqueTransaction,
+	hash: Option<H256>,
+) -> Result<Vec<u8>, ClientError> {
+	let len = u32::try_from(extrinsic.data.0.len()).expect("extrinsic size expected to be <4GB");
+	let mut call_data = extrinsic.data.0.clone();
+	len.encode_to(&mut call_data);
+
+	let mut params = RpcParams::new();
+	params.push(method)?;
+	params.push(std::format!("0x{}", hex::encode(call_data)))?;
+	params.push(hash.map(|x| x.to_hex_string()))?;
+
+	let encoded_value: String = request(client, "state_call", params).await?;
+
+	hex::decode(encoded_value.trim_start_matches("0x")).map_err(ClientError::from)
+}
+
+pub async fn tagged_transaction_queue_validate_transaction<T: RpcTransport + ?Sized>(
+	client: &T,
+	source: TransactionSource,
+	extrinsic: &OpaqueTransaction,
+	block_hash: H256,
+) -> Result<TransactionValidity, ClientError> {
+	let mut call_data = Vec::new();
+	source.encode_to(&mut call_data);
+	extrinsic.encode_to(&mut call_data);
+	block_hash.encode_to(&mut call_data);
+
+	let mut params = RpcParams::new();
+	params.push("TaggedTransactionQueue_validate_transaction")?;
+	params.push(std::format!("0x{}", hex::encode(call_data)))?;
+	params.push(Some(block_hash.to_hex_string()))?;
+
+	let encoded_value: String = request(client, "state_call", params).await?;
+	let encoded_value = hex::decode(encoded_value.trim_start_matches("0x"))?;
+
+	TransactionValidity::decode(&mut encoded_value.as_ref()).map_err(ClientError::from)
+}
+
+pub async fn fetch_best_block_hash<T: RpcTransport + ?Sized>(
+	client: &T,
+) -> Result<H256, ClientError> {
+	let value: String = request(client, "chain_getBlockHash", RpcParams::new()).await?;
 
 	H256::from_hex_string(&value).map_err(ClientError::from)
 }
 
-pub async fn fetch_finalized_block_hash(client: &JRPSHttpClient) -> Result<H256, ClientError> {
-	let value: Result<String, _> = client
-		.request::<_, _>("chain_getFinalizedHead", RpcParams::new())
-		.await;
-	let value: String = value.map_err(ClientError::from)?;
+/// Returns the hash of the block with `block_number` on the best chain, or `None` if there is no
+/// such block yet.
+pub async fn fetch_block_hash<T: RpcTransport + ?Sized>(
+	client: &T,
+	block_number: u32,
+) -> Result<Option<H256>, ClientError> {
+	let mut params = RpcParams::new();
+	params.push(block_number)?;
+
+	let value: Option<String> = request(client, "chain_getBlockHash", params).await?;
+	let Some(value) = value else {
+		return Ok(None);
+	};
+
+	Ok(Some(H256::from_hex_string(&value)?))
+}
+
+pub async fn fetch_finalized_block_hash<T: RpcTransport + ?Sized>(
+	client: &T,
+) -> Result<H256, ClientError> {
+	let value: String = request(client, "chain_getFinalizedHead", RpcParams::new()).await?;
 
 	H256::from_hex_string(&value).map_err(ClientError::from)
 }
 
-pub async fn chain_spec_v1_genesis_hash(client: &JRPSHttpClient) -> Result<H256, ClientError> {
-	let value: Result<String, _> = client
-		.request::<_, _>("chainSpec_v1_genesisHash", RpcParams::new())
-		.await;
-	let value: String = value.map_err(ClientError::from)?;
+pub async fn chain_spec_v1_genesis_hash<T: RpcTransport + ?Sized>(
+	client: &T,
+) -> Result<H256, ClientError> {
+	let value: String = request(client, "chainSpec_v1_genesisHash", RpcParams::new()).await?;
 
 	H256::from_hex_string(&value).map_err(ClientError::from)
 }
 
-pub async fn state_get_runtime_version(
-	client: &JRPSHttpClient,
+pub async fn state_get_runtime_version<T: RpcTransport + ?Sized>(
+	client: &T,
 ) -> Result<RuntimeVersion, ClientError> {
-	let value: Result<RuntimeVersion, _> = client
-		.request::<_, _>("state_getRuntimeVersion", RpcParams::new())
-		.await;
-
-	value.map_err(ClientError::from)
+	request(client, "state_getRuntimeVersion", RpcParams::new()).await
 }
 
-pub async fn fetch_block_header(
-	client: &JRPSHttpClient,
+pub async fn fetch_block_header<T: RpcTransport + ?Sized>(
+	client: &T,
 	hash: Option<H256>,
 ) -> Result<BlockHeader, ClientError> {
 	let mut params: RpcParams = RpcParams::new();
@@ -131,13 +219,11 @@ pub async fn fetch_block_header(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<BlockHeader, _> = client.request::<_, _>("chain_getHeader", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "chain_getHeader", params).await
 }
 
-pub async fn fetch_block(
-	client: &JRPSHttpClient,
+pub async fn fetch_block<T: RpcTransport + ?Sized>(
+	client: &T,
 	hash: Option<H256>,
 ) -> Result<SignedBlock, ClientError> {
 	let mut params: RpcParams = RpcParams::new();
@@ -145,28 +231,42 @@ pub async fn fetch_block(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<SignedBlock, _> = client.request::<_, _>("chain_getBlock", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "chain_getBlock", params).await
 }
 
-pub async fn author_submit_extrinsic(
-	client: &JRPSHttpClient,
+pub async fn author_submit_extrinsic<T: RpcTransport + ?Sized>(
+	client: &T,
 	extrinsic: OpaqueTransaction,
 ) -> Result<H256, ClientError> {
 	let mut params = RpcParams::new();
 	params.push(extrinsic.data.to_hex_string())?;
 
-	let value: Result<String, _> = client
-		.request::<_, _>("author_submitExtrinsic", params)
-		.await;
-	let value: String = value.map_err(ClientError::from)?;
+	let value: String = request(client, "author_submitExtrinsic", params).await?;
 
 	H256::from_hex_string(&value).map_err(ClientError::from)
 }
 
-pub async fn fetch_kate_block_length(
-	client: &JRPSHttpClient,
+/// Applies `extrinsic` on top of block `hash` (best block if `None`) without storing the result.
+/// The node has to expose unsafe RPCs for this to work.
+pub async fn system_dry_run<T: RpcTransport + ?Sized>(
+	client: &T,
+	extrinsic: &OpaqueTransaction,
+	hash: Option<H256>,
+) -> Result<ApplyExtrinsicResult, ClientError> {
+	let mut params = RpcParams::new();
+	params.push(extrinsic.data.to_hex_string())?;
+	if let Some(hash) = hash {
+		params.push(hash.to_hex_string())?;
+	}
+
+	let encoded_value: String = request(client, "system_dryRun", params).await?;
+	let encoded_value = hex::decode(encoded_value.trim_start_matches("0x"))?;
+
+	ApplyExtrinsicResult::decode(&mut encoded_value.as_ref()).map_err(ClientError::from)
+}
+
+pub async fn fetch_kate_block_length<T: RpcTransport + ?Sized>(
+	client: &T,
 	hash: Option<H256>,
 ) -> Result<BlockLength, ClientError> {
 	let mut params: RpcParams = RpcParams::new();
@@ -174,13 +274,11 @@ pub async fn fetch_kate_block_length(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<BlockLength, _> = client.request::<_, _>("kate_blockLength", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "kate_blockLength", params).await
 }
 
-pub async fn fetch_kate_query_data_proof(
-	client: &JRPSHttpClient,
+pub async fn fetch_kate_query_data_proof<T: RpcTransport + ?Sized>(
+	client: &T,
 	transaction_index: u32,
 	hash: Option<H256>,
 ) -> Result<ProofResponse, ClientError> {
@@ -190,14 +288,11 @@ pub async fn fetch_kate_query_data_proof(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<ProofResponse, _> =
-		client.request::<_, _>("kate_queryDataProof", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "kate_queryDataProof", params).await
 }
 
-pub async fn fetch_kate_query_proof(
-	client: &JRPSHttpClient,
+pub async fn fetch_kate_query_proof<T: RpcTransport + ?Sized>(
+	client: &T,
 	cells: Vec<Cell>,
 	hash: Option<H256>,
 ) -> Result<Vec<GDataProof>, ClientError> {
@@ -207,13 +302,11 @@ pub async fn fetch_kate_query_proof(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<Vec<GDataProof>, _> = client.request::<_, _>("kate_queryProof", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "kate_queryProof", params).await
 }
 
-pub async fn fetch_kate_query_rows(
-	client: &JRPSHttpClient,
+pub async fn fetch_kate_query_rows<T: RpcTransport + ?Sized>(
+	client: &T,
 	rows: Vec<u32>,
 	hash: Option<H256>,
 ) -> Result<Vec<GRow>, ClientError> {
@@ -223,7 +316,5 @@ pub async fn fetch_kate_query_rows(
 		params.push(hash.to_hex_string())?;
 	}
 
-	let value: Result<Vec<GRow>, _> = client.request::<_, _>("kate_queryRows", params).await;
-
-	value.map_err(ClientError::from)
+	request(client, "kate_queryRows", params).await
 }
@@ -0,0 +1,179 @@
+use crate::{
+	error::{ClientError, RpcError},
+	rpc::RpcParams,
+};
+use jsonrpsee_core::{async_trait, client::ClientT};
+use jsonrpsee_http_client::HttpClient;
+use std::{collections::HashMap, sync::Arc};
+
+/// Sends JSON-RPC requests to a node. Every function in [`rpc`](crate::rpc) goes through this
+/// trait, so anything from a WebSocket connection to an in-memory mock can be used.
+#[async_trait]
+pub trait RpcTransport: Send + Sync {
+	/// Sends a request and returns the `result` field of the response. Errors returned by the
+	/// node should be converted into [`ClientError::Rpc`].
+	async fn request_raw(
+		&self,
+		method: &str,
+		params: RpcParams,
+	) -> Result<serde_json::Value, ClientError>;
+}
+
+#[async_trait]
+impl RpcTransport for HttpClient {
+	async fn request_raw(
+		&self,
+		method: &str,
+		params: RpcParams,
+	) -> Result<serde_json::Value, ClientError> {
+		let value: Result<serde_json::Value, _> = self.request(method, params).await;
+		value.map_err(ClientError::from)
+	}
+}
+
+#[cfg(feature = "ws")]
+#[async_trait]
+impl RpcTransport for jsonrpsee_ws_client::WsClient {
+	async fn request_raw(
+		&self,
+		method: &str,
+		params: RpcParams,
+	) -> Result<serde_json::Value, ClientError> {
+		let value: Result<serde_json::Value, _> = self.request(method, params).await;
+		value.map_err(ClientError::from)
+	}
+}
+
+#[async_trait]
+impl<T: RpcTransport + ?Sized> RpcTransport for Arc<T> {
+	async fn request_raw(
+		&self,
+		method: &str,
+		params: RpcParams,
+	) -> Result<serde_json::Value, ClientError> {
+		self.as_ref().request_raw(method, params).await
+	}
+}
+
+/// In-memory transport that answers every request for a method with the same canned value,
+/// regardless of the params. Unknown methods fail with the JSON-RPC "Method not found" error.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+	responses: HashMap<String, serde_json::Value>,
+}
+
+impl MockTransport {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Answers requests for `method` with `result`.
+	pub fn with(mut self, method: &str, result: serde_json::Value) -> Self {
+		self.responses.insert(method.to_string(), result);
+		self
+	}
+}
+
+#[async_trait]
+impl RpcTransport for MockTransport {
+	async fn request_raw(
+		&self,
+		method: &str,
+		_params: RpcParams,
+	) -> Result<serde_json::Value, ClientError> {
+		match self.responses.get(method) {
+			Some(x) => Ok(x.clone()),
+			None => {
+				let error = RpcError::new(-32601, "Method not found".to_string(), None);
+				Err(ClientError::Rpc(error))
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		http::Client,
+		params::{Extra, Mortality, Nonce},
+	};
+	use parity_scale_codec::Compact;
+	use sdk_core::{
+		crypto::AccountId,
+		types::{self, avail::DataAvailabilityCalls, Additional, Era, UnsignedPayload, H256},
+	};
+	use serde_json::json;
+
+	fn mock() -> MockTransport {
+		let genesis_hash = H256([1u8; 32]).to_hex_string();
+		let best_hash = H256([2u8; 32]).to_hex_string();
+		let header = json!({
+			"parentHash": genesis_hash,
+			"number": "0x64",
+			"stateRoot": genesis_hash,
+			"extrinsicsRoot": genesis_hash,
+			"digest": { "logs": [] },
+			"extension": { "V3": {
+				"appLookup": { "size": 0, "index": [] },
+				"commitment": { "rows": 0, "cols": 0, "commitment": [], "dataRoot": genesis_hash },
+			}},
+		});
+		let runtime_version = json!({
+			"specName": "avail",
+			"implName": "avail",
+			"authoringVersion": 1,
+			"specVersion": 39,
+			"implVersion": 0,
+			"apis": [],
+			"transactionVersion": 1,
+			"stateVersion": 1,
+		});
+
+		MockTransport::new()
+			.with("chainSpec_v1_genesisHash", json!(genesis_hash))
+			.with("chain_getBlockHash", json!(best_hash))
+			.with("chain_getHeader", header)
+			.with("state_getRuntimeVersion", runtime_version)
+			.with("system_accountNextIndex", json!(7))
+	}
+
+	#[tokio::test]
+	async fn build_payload() {
+		let client = Client::with_transport(mock()).await.unwrap();
+		assert_eq!(client.genesis_hash(), H256([1u8; 32]));
+
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra::new()
+			.nonce(Nonce::BestBlockAndTxPool)
+			.mortality(Mortality::Period(16))
+			.tip(5);
+		let payload = client
+			.build_payload(call.clone(), AccountId([3u8; 32]), extra)
+			.await
+			.unwrap();
+
+		let extra = types::Extra {
+			mortality: Era::mortal(16, 100),
+			nonce: Compact(7),
+			tip: Compact(5),
+			app_id: Compact(0),
+		};
+		let additional = Additional::new(39, 1, H256([1u8; 32]), H256([2u8; 32]));
+		assert_eq!(
+			payload,
+			UnsignedPayload::new(call, extra, additional).encode()
+		);
+	}
+
+	#[tokio::test]
+	async fn unknown_method() {
+		let error = Client::with_transport(MockTransport::new())
+			.await
+			.unwrap_err();
+		assert!(matches!(
+			error,
+			ClientError::Rpc(RpcError::Other { code: -32601, .. })
+		));
+	}
+}
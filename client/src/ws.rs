@@ -0,0 +1,91 @@
+use crate::{error::ClientError, http::Client, nonce, rpc::RpcParams};
+use jsonrpsee_core::client::{Subscription, SubscriptionClientT};
+use jsonrpsee_ws_client::{WsClient, WsClientBuilder};
+use sdk_core::types::{
+	avail::{BlockHeader, TransactionStatus},
+	OpaqueTransaction,
+};
+
+/// Stream of new best block headers.
+pub async fn chain_subscribe_new_heads(
+	client: &WsClient,
+) -> Result<Subscription<BlockHeader>, ClientError> {
+	let value = client
+		.subscribe(
+			"chain_subscribeNewHeads",
+			RpcParams::new(),
+			"chain_unsubscribeNewHeads",
+		)
+		.await;
+
+	value.map_err(ClientError::from)
+}
+
+/// Stream of new finalized block headers.
+pub async fn chain_subscribe_finalized_heads(
+	client: &WsClient,
+) -> Result<Subscription<BlockHeader>, ClientError> {
+	let value = client
+		.subscribe(
+			"chain_subscribeFinalizedHeads",
+			RpcParams::new(),
+			"chain_unsubscribeFinalizedHeads",
+		)
+		.await;
+
+	value.map_err(ClientError::from)
+}
+
+/// Submits `extrinsic` and returns a stream of its status updates. The stream ends once a final
+/// status (see [`TransactionStatus::is_final`]) has been sent.
+pub async fn author_submit_and_watch_extrinsic(
+	client: &WsClient,
+	extrinsic: OpaqueTransaction,
+) -> Result<Subscription<TransactionStatus>, ClientError> {
+	let mut params = RpcParams::new();
+	params.push(extrinsic.data.to_hex_string())?;
+
+	let value = client
+		.subscribe(
+			"author_submitAndWatchExtrinsic",
+			params,
+			"author_unwatchExtrinsic",
+		)
+		.await;
+
+	value.map_err(ClientError::from)
+}
+
+impl Client<WsClient> {
+	/// Connects to a node over WebSocket, e.g. `ws://127.0.0.1:9944`. `wss://` endpoints are
+	/// verified against the root certificates of the system.
+	pub async fn new_ws(endpoint: &str) -> Result<Self, ClientError> {
+		let client = WsClientBuilder::default().build(endpoint).await;
+		let client = client.map_err(ClientError::from)?;
+
+		Self::with_transport(client).await
+	}
+
+	pub async fn subscribe_new_heads(&self) -> Result<Subscription<BlockHeader>, ClientError> {
+		chain_subscribe_new_heads(&self.client).await
+	}
+
+	pub async fn subscribe_finalized_heads(
+		&self,
+	) -> Result<Subscription<BlockHeader>, ClientError> {
+		chain_subscribe_finalized_heads(&self.client).await
+	}
+
+	/// Like [`Client::submit_transaction`], but returns a stream of status updates instead of
+	/// the transaction hash.
+	pub async fn submit_and_watch_transaction(
+		&self,
+		transaction: OpaqueTransaction,
+	) -> Result<Subscription<TransactionStatus>, ClientError> {
+		let signer = nonce::signer_and_nonce(&transaction);
+		let result = author_submit_and_watch_extrinsic(&self.client, transaction).await;
+		self.report_submission(signer, result.as_ref().err()).await;
+
+		result
+	}
+}
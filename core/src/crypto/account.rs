@@ -1,7 +1,8 @@
-use super::{ss58::ByteArray, Ss58Codec};
-use parity_scale_codec::Encode;
+use super::{blake2_256, ss58::ByteArray, Ss58Codec};
+use crate::types::error::CoreError;
+use parity_scale_codec::{Decode, Encode};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AccountId(pub [u8; 32]);
 impl AccountId {
 	pub fn to_hex_string(&self) -> String {
@@ -17,6 +18,13 @@ impl Encode for AccountId {
 		self.0.encode_to(dest)
 	}
 }
+impl Decode for AccountId {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		Ok(Self(<[u8; 32]>::decode(input)?))
+	}
+}
 impl AsRef<[u8]> for AccountId {
 	fn as_ref(&self) -> &[u8] {
 		&self.0[..]
@@ -48,3 +56,49 @@ impl ByteArray for AccountId {
 	const LEN: usize = 32;
 }
 impl Ss58Codec for AccountId {}
+
+/// Derives the account id of a multisig account the same way `pallet_multisig` does.
+///
+/// `signatories` must contain all members of the multisig, including the caller. The order does
+/// not matter, they are sorted before hashing. Fails if a signatory is listed twice, since the
+/// pallet rejects such a multisig.
+pub fn multisig_account_id(
+	signatories: &[AccountId],
+	threshold: u16,
+) -> Result<AccountId, CoreError> {
+	let mut who = signatories.to_vec();
+	who.sort();
+	if let Some(x) = who.windows(2).find(|x| x[0] == x[1]) {
+		let msg = std::format!("Duplicate multisig signatory {}", x[0].to_ss58check());
+		return Err(CoreError::ConversionError(msg));
+	}
+
+	let entropy = (b"modlpy/utilisuba", who, threshold).using_encoded(blake2_256);
+	Ok(AccountId(entropy))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn multisig_account_id_ignores_order() {
+		let (a, b, c) = (
+			AccountId([1u8; 32]),
+			AccountId([2u8; 32]),
+			AccountId([3u8; 32]),
+		);
+		let expected = multisig_account_id(&[a, b, c], 2).unwrap();
+
+		assert_eq!(multisig_account_id(&[c, a, b], 2).unwrap(), expected);
+		assert_ne!(multisig_account_id(&[a, b, c], 3).unwrap(), expected);
+	}
+
+	#[test]
+	fn multisig_account_id_rejects_duplicates() {
+		let (a, b) = (AccountId([1u8; 32]), AccountId([2u8; 32]));
+
+		assert!(multisig_account_id(&[a, a, b], 2).is_err());
+		assert!(multisig_account_id(&[b, a, b], 2).is_err());
+	}
+}
@@ -0,0 +1,221 @@
+use std::fmt::Display;
+
+use super::{
+	blake2_256, derive_junction::DeriveJunction, secret_uri::SecretUri, sr25519::seed_from_entropy,
+	AccountId,
+};
+use k256::ecdsa::{RecoveryId, SigningKey, VerifyingKey};
+use parity_scale_codec::Encode;
+
+/// A signature generated by [`Keypair::sign()`]. These bytes are equivalent
+/// to a Substrate `MultiSignature::Ecdsa(bytes)`: 64 bytes of signature followed by the
+/// recovery id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature(pub [u8; 65]);
+impl Encode for Signature {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.0.encode_to(dest);
+	}
+}
+
+/// The compressed public key for an [`Keypair`] key pair. Unlike sr25519 and ed25519 keys, the
+/// Substrate `AccountId32` is the blake2_256 hash of these bytes.
+pub struct PublicKey(pub [u8; 33]);
+
+impl PublicKey {
+	pub fn to_account_id(self) -> AccountId {
+		AccountId(blake2_256(&self.0))
+	}
+}
+
+impl Encode for PublicKey {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.0.encode_to(dest);
+	}
+}
+
+const SECRET_KEY_LENGTH: usize = 32;
+/// Seed bytes used to generate a key pair.
+pub type SecretKeyBytes = [u8; SECRET_KEY_LENGTH];
+
+#[derive(Debug, Clone)]
+pub struct Keypair(SigningKey);
+
+impl Keypair {
+	/// Signs the blake2_256 hash of `message`, the same way Substrate does.
+	pub fn sign(&self, message: &[u8]) -> Signature {
+		let message_hash = blake2_256(message);
+		let (signature, recovery_id) = self
+			.0
+			.sign_prehash_recoverable(&message_hash)
+			.expect("signing a 32 byte hash cannot fail; qed");
+
+		let mut bytes = [0u8; 65];
+		bytes[..64].copy_from_slice(&signature.to_bytes());
+		bytes[64] = recovery_id.to_byte();
+		Signature(bytes)
+	}
+
+	pub fn verify(sig: &Signature, message: &[u8], public_key: PublicKey) -> bool {
+		Self::recover(sig, message).is_some_and(|x| x.0 == public_key.0)
+	}
+
+	/// Recovers the public key that produced `sig` for `message`.
+	pub fn recover(sig: &Signature, message: &[u8]) -> Option<PublicKey> {
+		let signature = k256::ecdsa::Signature::from_slice(&sig.0[..64]).ok()?;
+		let recovery_id = RecoveryId::from_byte(sig.0[64])?;
+
+		let message_hash = blake2_256(message);
+		let recovered =
+			VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id).ok()?;
+		let bytes = recovered
+			.to_encoded_point(true)
+			.as_bytes()
+			.try_into()
+			.ok()?;
+		Some(PublicKey(bytes))
+	}
+
+	/// Create an ecdsa keypair from a [`SecretUri`]. See the [`SecretUri`] docs for more.
+	///
+	/// Only hard junctions (`//`) are supported. Soft junctions return [`Error::SoftJunction`].
+	pub fn from_uri(uri: &SecretUri) -> Result<Self, Error> {
+		use hex::FromHex;
+		use secrecy::ExposeSecret;
+		use std::str::FromStr;
+
+		let SecretUri {
+			junctions,
+			phrase,
+			password,
+		} = uri;
+
+		// If the phrase is hex, convert bytes directly into a seed, ignoring password.
+		// Else, parse the phrase string taking the password into account. This is
+		// the same approach taken in sp_core::crypto::Pair::from_string_with_seed.
+		let key = if let Some(hex_str) = phrase.expose_secret().strip_prefix("0x") {
+			let seed = SecretKeyBytes::from_hex(hex_str)?;
+			Self::from_secret_key(seed)?
+		} else {
+			let phrase = bip39::Mnemonic::from_str(phrase.expose_secret().as_str())?;
+			let pass_str = password.as_ref().map(|p| p.expose_secret().as_str());
+			Self::from_phrase(&phrase, pass_str)?
+		};
+
+		// Now, use any "junctions" to derive a new key from this root key.
+		key.derive(junctions.iter().copied())
+	}
+
+	/// Create an ecdsa keypair from a BIP-39 mnemonic phrase and optional password.
+	pub fn from_phrase(mnemonic: &bip39::Mnemonic, password: Option<&str>) -> Result<Self, Error> {
+		let (arr, len) = mnemonic.to_entropy_array();
+		let big_seed =
+			seed_from_entropy(&arr[0..len], password.unwrap_or("")).ok_or(Error::InvalidSeed)?;
+
+		let seed: SecretKeyBytes = big_seed[..SECRET_KEY_LENGTH]
+			.try_into()
+			.expect("should be valid Seed");
+
+		Self::from_secret_key(seed)
+	}
+
+	/// Turn a 32 byte secret key into a keypair. Fails if the bytes are not a valid secp256k1
+	/// scalar.
+	///
+	/// # Warning
+	///
+	/// This will only be secure if the seed is secure!
+	pub fn from_secret_key(secret_key_bytes: SecretKeyBytes) -> Result<Self, Error> {
+		let secret = SigningKey::from_slice(&secret_key_bytes).map_err(|_| Error::InvalidSeed)?;
+		Ok(Keypair(secret))
+	}
+
+	/// Derive a child key from this one given a series of junctions.
+	///
+	/// Ecdsa only supports hard derivation, the same way Substrate does it. Any soft junction
+	/// results in [`Error::SoftJunction`].
+	pub fn derive<Js: IntoIterator<Item = DeriveJunction>>(
+		&self,
+		junctions: Js,
+	) -> Result<Self, Error> {
+		let mut seed: SecretKeyBytes = self.0.to_bytes().into();
+		for junction in junctions {
+			match junction {
+				DeriveJunction::Soft(_) => return Err(Error::SoftJunction),
+				DeriveJunction::Hard(cc) => {
+					seed = ("Secp256k1HDKD", seed, cc).using_encoded(blake2_256);
+				},
+			}
+		}
+
+		Self::from_secret_key(seed)
+	}
+
+	pub fn account_id(&self) -> AccountId {
+		self.public_key().to_account_id()
+	}
+
+	/// Obtain the compressed [`PublicKey`] part of this key pair, which can be used in calls to
+	/// [`verify()`].
+	pub fn public_key(&self) -> PublicKey {
+		let point = self.0.verifying_key().to_encoded_point(true);
+		let bytes: [u8; 33] = point
+			.as_bytes()
+			.try_into()
+			.expect("compressed public key is 33 bytes; qed");
+		PublicKey(bytes)
+	}
+}
+
+/// An error handed back if creating a keypair fails.
+#[derive(Debug)]
+pub enum Error {
+	/// Invalid seed.
+	InvalidSeed,
+	/// Invalid phrase.
+	Phrase(bip39::Error),
+	/// Invalid hex.
+	Hex(hex::FromHexError),
+	/// Soft junctions are not supported by ecdsa.
+	SoftJunction,
+}
+
+impl From<bip39::Error> for Error {
+	fn from(value: bip39::Error) -> Self {
+		Error::Phrase(value)
+	}
+}
+
+impl From<hex::FromHexError> for Error {
+	fn from(value: hex::FromHexError) -> Self {
+		Error::Hex(value)
+	}
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Error::InvalidSeed => write!(f, "Invalid seed (was it the wrong length?)"),
+			Error::Phrase(e) => write!(f, "Cannot parse phrase: {e}"),
+			Error::Hex(e) => write!(f, "Cannot parse hex string: {e}"),
+			Error::SoftJunction => write!(f, "Soft junctions are not supported by ecdsa"),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Hex(e) => Some(e),
+			_ => None,
+		}
+	}
+}
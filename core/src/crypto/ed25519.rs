@@ -0,0 +1,191 @@
+use std::fmt::Display;
+
+use super::{
+	blake2_256, derive_junction::DeriveJunction, secret_uri::SecretUri, sr25519::seed_from_entropy,
+	AccountId,
+};
+use parity_scale_codec::Encode;
+
+/// A signature generated by [`Keypair::sign()`]. These bytes are equivalent
+/// to a Substrate `MultiSignature::Ed25519(bytes)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature(pub [u8; 64]);
+impl Encode for Signature {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.0.encode_to(dest);
+	}
+}
+
+/// The public key for an [`Keypair`] key pair. This is equivalent to a
+/// Substrate `AccountId32`.
+pub struct PublicKey(pub [u8; 32]);
+
+impl PublicKey {
+	pub fn to_account_id(self) -> AccountId {
+		AccountId(self.0)
+	}
+}
+
+impl Encode for PublicKey {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.0.encode_to(dest);
+	}
+}
+
+const SECRET_KEY_LENGTH: usize = 32;
+/// Seed bytes used to generate a key pair.
+pub type SecretKeyBytes = [u8; SECRET_KEY_LENGTH];
+
+#[derive(Debug, Clone)]
+pub struct Keypair(ed25519_zebra::SigningKey);
+
+impl Keypair {
+	pub fn sign(&self, message: &[u8]) -> Signature {
+		Signature(self.0.sign(message).to_bytes())
+	}
+
+	pub fn verify(sig: &Signature, message: &[u8], public_key: PublicKey) -> bool {
+		let signature = ed25519_zebra::Signature::from_bytes(&sig.0);
+		let Ok(public) = ed25519_zebra::VerificationKey::try_from(public_key.0) else {
+			return false;
+		};
+		public.verify(&signature, message).is_ok()
+	}
+
+	/// Create an ed25519 keypair from a [`SecretUri`]. See the [`SecretUri`] docs for more.
+	///
+	/// Only hard junctions (`//`) are supported. Soft junctions return [`Error::SoftJunction`].
+	pub fn from_uri(uri: &SecretUri) -> Result<Self, Error> {
+		use hex::FromHex;
+		use secrecy::ExposeSecret;
+		use std::str::FromStr;
+
+		let SecretUri {
+			junctions,
+			phrase,
+			password,
+		} = uri;
+
+		// If the phrase is hex, convert bytes directly into a seed, ignoring password.
+		// Else, parse the phrase string taking the password into account. This is
+		// the same approach taken in sp_core::crypto::Pair::from_string_with_seed.
+		let key = if let Some(hex_str) = phrase.expose_secret().strip_prefix("0x") {
+			let seed = SecretKeyBytes::from_hex(hex_str)?;
+			Self::from_secret_key(seed)?
+		} else {
+			let phrase = bip39::Mnemonic::from_str(phrase.expose_secret().as_str())?;
+			let pass_str = password.as_ref().map(|p| p.expose_secret().as_str());
+			Self::from_phrase(&phrase, pass_str)?
+		};
+
+		// Now, use any "junctions" to derive a new key from this root key.
+		key.derive(junctions.iter().copied())
+	}
+
+	/// Create an ed25519 keypair from a BIP-39 mnemonic phrase and optional password.
+	pub fn from_phrase(mnemonic: &bip39::Mnemonic, password: Option<&str>) -> Result<Self, Error> {
+		let (arr, len) = mnemonic.to_entropy_array();
+		let big_seed =
+			seed_from_entropy(&arr[0..len], password.unwrap_or("")).ok_or(Error::InvalidSeed)?;
+
+		let seed: SecretKeyBytes = big_seed[..SECRET_KEY_LENGTH]
+			.try_into()
+			.expect("should be valid Seed");
+
+		Self::from_secret_key(seed)
+	}
+
+	/// Turn a 32 byte secret key into a keypair.
+	///
+	/// # Warning
+	///
+	/// This will only be secure if the seed is secure!
+	pub fn from_secret_key(secret_key_bytes: SecretKeyBytes) -> Result<Self, Error> {
+		Ok(Keypair(ed25519_zebra::SigningKey::from(secret_key_bytes)))
+	}
+
+	/// Derive a child key from this one given a series of junctions.
+	///
+	/// Ed25519 only supports hard derivation, the same way Substrate does it. Any soft junction
+	/// results in [`Error::SoftJunction`].
+	pub fn derive<Js: IntoIterator<Item = DeriveJunction>>(
+		&self,
+		junctions: Js,
+	) -> Result<Self, Error> {
+		let mut seed = self.0.to_bytes();
+		for junction in junctions {
+			match junction {
+				DeriveJunction::Soft(_) => return Err(Error::SoftJunction),
+				DeriveJunction::Hard(cc) => {
+					seed = ("Ed25519HDKD", seed, cc).using_encoded(blake2_256);
+				},
+			}
+		}
+
+		Self::from_secret_key(seed)
+	}
+
+	pub fn account_id(&self) -> AccountId {
+		self.public_key().to_account_id()
+	}
+
+	/// Obtain the [`PublicKey`] part of this key pair, which can be used in calls to [`verify()`].
+	/// or otherwise converted into an address. The public key bytes are equivalent to a Substrate
+	/// `AccountId32`.
+	pub fn public_key(&self) -> PublicKey {
+		PublicKey(self.0.verification_key().into())
+	}
+}
+
+/// An error handed back if creating a keypair fails.
+#[derive(Debug)]
+pub enum Error {
+	/// Invalid seed.
+	InvalidSeed,
+	/// Invalid phrase.
+	Phrase(bip39::Error),
+	/// Invalid hex.
+	Hex(hex::FromHexError),
+	/// Soft junctions are not supported by ed25519.
+	SoftJunction,
+}
+
+impl From<bip39::Error> for Error {
+	fn from(value: bip39::Error) -> Self {
+		Error::Phrase(value)
+	}
+}
+
+impl From<hex::FromHexError> for Error {
+	fn from(value: hex::FromHexError) -> Self {
+		Error::Hex(value)
+	}
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Error::InvalidSeed => write!(f, "Invalid seed (was it the wrong length?)"),
+			Error::Phrase(e) => write!(f, "Cannot parse phrase: {e}"),
+			Error::Hex(e) => write!(f, "Cannot parse hex string: {e}"),
+			Error::SoftJunction => write!(f, "Soft junctions are not supported by ed25519"),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Hex(e) => Some(e),
+			_ => None,
+		}
+	}
+}
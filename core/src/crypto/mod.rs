@@ -1,11 +1,16 @@
 mod account;
 mod derive_junction;
+pub mod ecdsa;
+pub mod ed25519;
 mod secret_uri;
-mod sr25519;
+mod signer;
+pub mod sr25519;
 mod ss58;
 
-pub use account::AccountId;
+pub use account::{multisig_account_id, AccountId};
 pub use secret_uri::SecretUri;
+pub(crate) use signer::sign_checked;
+pub use signer::Signer;
 pub use sr25519::{Keypair, PublicKey, Signature};
 pub use ss58::Ss58Codec;
 
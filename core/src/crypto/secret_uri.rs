@@ -132,7 +132,6 @@ impl Display for SecretUriError {
 	}
 }
 
-#[cfg(feature = "std")]
 impl std::error::Error for SecretUriError {}
 
 /// Interpret a phrase like:
@@ -0,0 +1,115 @@
+use super::{ecdsa, ed25519, AccountId, Keypair};
+use crate::types::{
+	error::CoreError,
+	multi::{MultiAddress, MultiSignature, SignatureKind},
+	UnsignedEncodedPayload,
+};
+
+/// Something that can sign transactions.
+///
+/// Transactions are built through this trait so that keys kept outside of this crate (hardware
+/// wallets, remote signers, ...) can be used without touching the transaction encoding.
+pub trait Signer {
+	/// Account used to look up the nonce of the signer.
+	fn account_id(&self) -> AccountId;
+
+	/// Address attached to the transaction.
+	fn address(&self) -> MultiAddress {
+		MultiAddress::Id(self.account_id())
+	}
+
+	/// Signature scheme of the signatures returned by [`Signer::sign`].
+	fn signature_kind(&self) -> SignatureKind;
+
+	/// Signs raw bytes.
+	fn sign(&self, message: &[u8]) -> MultiSignature;
+
+	/// Signs a transaction payload. Payloads longer than 256 bytes are hashed with blake2_256
+	/// first, as the runtime expects.
+	fn sign_payload(&self, payload: &UnsignedEncodedPayload) -> MultiSignature {
+		payload.using_signing_bytes(|bytes| self.sign(bytes))
+	}
+}
+
+/// Signs `payload` and checks that the signature has the kind announced by
+/// [`Signer::signature_kind`].
+pub(crate) fn sign_checked<S: Signer + ?Sized>(
+	signer: &S,
+	payload: &UnsignedEncodedPayload,
+) -> Result<MultiSignature, CoreError> {
+	let signature = signer.sign_payload(payload);
+	if signature.kind() != signer.signature_kind() {
+		let msg = std::format!(
+			"Signer returned a {:?} signature but announced {:?}",
+			signature.kind(),
+			signer.signature_kind()
+		);
+		return Err(CoreError::ConversionError(msg));
+	}
+
+	Ok(signature)
+}
+
+impl Signer for Keypair {
+	fn account_id(&self) -> AccountId {
+		Keypair::account_id(self)
+	}
+
+	fn signature_kind(&self) -> SignatureKind {
+		SignatureKind::Sr25519
+	}
+
+	fn sign(&self, message: &[u8]) -> MultiSignature {
+		Keypair::sign(self, message).into()
+	}
+}
+
+impl Signer for ed25519::Keypair {
+	fn account_id(&self) -> AccountId {
+		ed25519::Keypair::account_id(self)
+	}
+
+	fn signature_kind(&self) -> SignatureKind {
+		SignatureKind::Ed25519
+	}
+
+	fn sign(&self, message: &[u8]) -> MultiSignature {
+		MultiSignature::Ed25519(ed25519::Keypair::sign(self, message).0)
+	}
+}
+
+impl Signer for ecdsa::Keypair {
+	fn account_id(&self) -> AccountId {
+		ecdsa::Keypair::account_id(self)
+	}
+
+	fn signature_kind(&self) -> SignatureKind {
+		SignatureKind::Ecdsa
+	}
+
+	fn sign(&self, message: &[u8]) -> MultiSignature {
+		MultiSignature::Ecdsa(ecdsa::Keypair::sign(self, message).0)
+	}
+}
+
+impl<S: Signer + ?Sized> Signer for &S {
+	fn account_id(&self) -> AccountId {
+		(**self).account_id()
+	}
+
+	fn address(&self) -> MultiAddress {
+		(**self).address()
+	}
+
+	fn signature_kind(&self) -> SignatureKind {
+		(**self).signature_kind()
+	}
+
+	fn sign(&self, message: &[u8]) -> MultiSignature {
+		(**self).sign(message)
+	}
+
+	fn sign_payload(&self, payload: &UnsignedEncodedPayload) -> MultiSignature {
+		(**self).sign_payload(payload)
+	}
+}
@@ -221,6 +221,15 @@ impl Display for Error {
 	}
 }
 
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Hex(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
 /// This is taken from `substrate-bip39` so that we can keep dependencies in line, and
 /// is the same logic that sp-core uses to go from mnemonic entropy to seed. Returns
 /// `None` if invalid length.
@@ -1,11 +1,23 @@
 use super::{AlreadyEncoded, H256};
 use crate::types::payload_fields::Call;
-use parity_scale_codec::{Decode, Encode};
+use crate::{
+	crypto::{blake2_256, AccountId},
+	types::multi::MultiAddress,
+};
+use parity_scale_codec::{Compact, Decode, Encode};
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use super::payload_fields::{Period, Phase};
+pub use calls::balances as BalancesCalls;
 pub use calls::data_availability as DataAvailabilityCalls;
+pub use calls::multisig as MultisigCalls;
+pub use calls::proxy as ProxyCalls;
+pub use calls::staking as StakingCalls;
+pub use calls::sudo as SudoCalls;
+pub use calls::utility as UtilityCalls;
+pub use calls::vector as VectorCalls;
 
+pub type Balance = u128;
 pub type Tip = u128;
 pub type Nonce = u32;
 pub type AppId = u32;
@@ -31,13 +43,167 @@ pub struct RuntimeVersion {
 	pub state_version: u8,
 }
 
+/// Status of a transaction watched with `author_submitAndWatchExtrinsic`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionStatus {
+	/// The transaction is part of the future queue.
+	Future,
+	/// The transaction is part of the ready queue.
+	Ready,
+	/// The transaction has been broadcast to the given peers.
+	Broadcast(Vec<String>),
+	/// The transaction has been included in the block with the given hash.
+	InBlock(H256),
+	/// The block the transaction was included in has been retracted.
+	Retracted(H256),
+	/// The block the transaction was included in was not finalized within 512 blocks. The
+	/// node stops watching the transaction.
+	FinalityTimeout(H256),
+	/// The block the transaction was included in has been finalized.
+	Finalized(H256),
+	/// The transaction has been replaced in the pool by another one with the same tags.
+	Usurped(H256),
+	/// The transaction has been dropped from the pool, e.g. because the pool is full.
+	Dropped,
+	/// The transaction is no longer valid in the current state.
+	Invalid,
+}
+impl TransactionStatus {
+	/// Returns true if no further status will follow this one.
+	pub fn is_final(&self) -> bool {
+		matches!(
+			self,
+			Self::FinalityTimeout(_)
+				| Self::Finalized(_)
+				| Self::Usurped(_)
+				| Self::Dropped
+				| Self::Invalid
+		)
+	}
+}
+
+/// Weight of a dispatchable, in computational time and proof size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Weight {
+	/// Computational time used to execute some logic, in picoseconds.
+	pub ref_time: u64,
+	/// Storage size of the proof for this weight, in bytes.
+	pub proof_size: u64,
+}
+impl Weight {
+	pub fn new(ref_time: u64, proof_size: u64) -> Self {
+		Self {
+			ref_time,
+			proof_size,
+		}
+	}
+}
+impl Encode for Weight {
+	fn size_hint(&self) -> usize {
+		Compact(self.ref_time).size_hint() + Compact(self.proof_size).size_hint()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		Compact(self.ref_time).encode_to(dest);
+		Compact(self.proof_size).encode_to(dest);
+	}
+}
+impl Decode for Weight {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let ref_time = Compact::<u64>::decode(input)?.0;
+		let proof_size = Compact::<u64>::decode(input)?.0;
+
+		Ok(Self::new(ref_time, proof_size))
+	}
+}
+
 #[repr(u8)]
 pub enum Pallet {
+	Utility = 1,
+	Balances = 6,
+	Staking = 10,
+	Sudo = 19,
+	Multisig = 34,
+	Vector = 39,
+	Proxy = 40,
 	DataAvailability = 29,
 }
 
 pub mod calls {
 	use super::*;
+	use parity_scale_codec::DecodeAll;
+
+	/// A call decoded into its arguments. Calls that are not known to this crate are kept as they
+	/// are, since decoding them would require the runtime metadata.
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	pub enum DecodedCall {
+		DataAvailability(data_availability::DataAvailabilityCall),
+		Opaque(Call),
+	}
+	impl DecodedCall {
+		pub fn from_call(call: Call) -> Self {
+			let decoded = match call.pallet_index {
+				x if x == Pallet::DataAvailability as u8 => {
+					data_availability::DataAvailabilityCall::from_call(&call)
+						.map(Self::DataAvailability)
+				},
+				_ => None,
+			};
+
+			decoded.unwrap_or(Self::Opaque(call))
+		}
+	}
+
+	// Human readable form of the call, e.g. `DataAvailability::submit_data(data: 0x0102)`.
+	impl std::fmt::Display for DecodedCall {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			use data_availability::DataAvailabilityCall as DA;
+
+			let hex = |x: &[u8]| std::format!("0x{}", hex::encode(x));
+			match self {
+				Self::DataAvailability(DA::CreateApplicationKey { key }) => {
+					write!(
+						f,
+						"DataAvailability::create_application_key(key: {})",
+						hex(key)
+					)
+				},
+				Self::DataAvailability(DA::SubmitData { data }) => {
+					write!(f, "DataAvailability::submit_data(data: {})", hex(data))
+				},
+				Self::DataAvailability(DA::SubmitBlockLengthProposal { rows, cols }) => write!(
+					f,
+					"DataAvailability::submit_block_length_proposal(rows: {rows}, cols: {cols})"
+				),
+				Self::DataAvailability(DA::SetApplicationKey { old_key, new_key }) => write!(
+					f,
+					"DataAvailability::set_application_key(old_key: {}, new_key: {})",
+					hex(old_key),
+					hex(new_key)
+				),
+				Self::DataAvailability(DA::SetSubmitDataFeeModifier { modifier }) => write!(
+					f,
+					"DataAvailability::set_submit_data_fee_modifier(modifier: {modifier:?})"
+				),
+				Self::Opaque(call) => write!(
+					f,
+					"pallet {} call {}(fields: {})",
+					call.pallet_index,
+					call.call_index,
+					call.fields.to_hex_string()
+				),
+			}
+		}
+	}
+
+	// Returns `None` if the fields cannot be decoded or if not all bytes were consumed.
+	fn decode_fields<T: Decode>(fields: &AlreadyEncoded) -> Option<T> {
+		T::decode_all(&mut fields.0.as_slice()).ok()
+	}
+
 	pub mod data_availability {
 		use super::*;
 
@@ -45,6 +211,84 @@ pub mod calls {
 		pub enum Dispatchable {
 			CreateApplicationKey = 0,
 			SubmitData = 1,
+			SubmitBlockLengthProposal = 2,
+			SetApplicationKey = 3,
+			SetSubmitDataFeeModifier = 4,
+		}
+
+		/// Modifies how the weight part of the `submit_data` fee is computed.
+		#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+		pub struct DispatchFeeModifier {
+			/// Upper bound of the weight fee.
+			pub weight_maximum_fee: Option<Balance>,
+			/// The weight fee is divided by this value.
+			pub weight_fee_divider: Option<u32>,
+			/// The weight fee is multiplied by this value.
+			pub weight_fee_multiplier: Option<u32>,
+		}
+		impl Encode for DispatchFeeModifier {
+			fn size_hint(&self) -> usize {
+				self.weight_maximum_fee.size_hint()
+					+ self.weight_fee_divider.size_hint()
+					+ self.weight_fee_multiplier.size_hint()
+			}
+
+			fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.weight_maximum_fee.encode_to(dest);
+				self.weight_fee_divider.encode_to(dest);
+				self.weight_fee_multiplier.encode_to(dest);
+			}
+		}
+		impl Decode for DispatchFeeModifier {
+			fn decode<I: parity_scale_codec::Input>(
+				input: &mut I,
+			) -> Result<Self, parity_scale_codec::Error> {
+				Ok(Self {
+					weight_maximum_fee: Option::<Balance>::decode(input)?,
+					weight_fee_divider: Option::<u32>::decode(input)?,
+					weight_fee_multiplier: Option::<u32>::decode(input)?,
+				})
+			}
+		}
+
+		#[derive(Debug, Clone, PartialEq, Eq)]
+		pub enum DataAvailabilityCall {
+			CreateApplicationKey { key: Vec<u8> },
+			SubmitData { data: Vec<u8> },
+			SubmitBlockLengthProposal { rows: u32, cols: u32 },
+			SetApplicationKey { old_key: Vec<u8>, new_key: Vec<u8> },
+			SetSubmitDataFeeModifier { modifier: DispatchFeeModifier },
+		}
+		impl DataAvailabilityCall {
+			pub fn from_call(call: &Call) -> Option<Self> {
+				if call.pallet_index != Pallet::DataAvailability as u8 {
+					return None;
+				}
+
+				match call.call_index {
+					x if x == Dispatchable::CreateApplicationKey as u8 => {
+						let key = decode_fields::<Vec<u8>>(&call.fields)?;
+						Some(Self::CreateApplicationKey { key })
+					},
+					x if x == Dispatchable::SubmitData as u8 => {
+						let data = decode_fields::<Vec<u8>>(&call.fields)?;
+						Some(Self::SubmitData { data })
+					},
+					x if x == Dispatchable::SubmitBlockLengthProposal as u8 => {
+						let (rows, cols) = decode_fields::<(u32, u32)>(&call.fields)?;
+						Some(Self::SubmitBlockLengthProposal { rows, cols })
+					},
+					x if x == Dispatchable::SetApplicationKey as u8 => {
+						let (old_key, new_key) = decode_fields::<(Vec<u8>, Vec<u8>)>(&call.fields)?;
+						Some(Self::SetApplicationKey { old_key, new_key })
+					},
+					x if x == Dispatchable::SetSubmitDataFeeModifier as u8 => {
+						let modifier = decode_fields::<DispatchFeeModifier>(&call.fields)?;
+						Some(Self::SetSubmitDataFeeModifier { modifier })
+					},
+					_ => None,
+				}
+			}
 		}
 
 		pub fn create_application_key(key: Vec<u8>) -> Call {
@@ -62,6 +306,558 @@ pub mod calls {
 				AlreadyEncoded(data.encode()),
 			)
 		}
+
+		/// Proposes new block dimensions. Requires root origin, see [`super::sudo::sudo`].
+		pub fn submit_block_length_proposal(rows: u32, cols: u32) -> Call {
+			Call::new(
+				Pallet::DataAvailability as u8,
+				Dispatchable::SubmitBlockLengthProposal as u8,
+				AlreadyEncoded((rows, cols).encode()),
+			)
+		}
+
+		/// Renames an existing application key. Requires root origin.
+		pub fn set_application_key(old_key: Vec<u8>, new_key: Vec<u8>) -> Call {
+			Call::new(
+				Pallet::DataAvailability as u8,
+				Dispatchable::SetApplicationKey as u8,
+				AlreadyEncoded((old_key, new_key).encode()),
+			)
+		}
+
+		/// Sets how the `submit_data` fee is computed. Requires root origin.
+		pub fn set_submit_data_fee_modifier(modifier: DispatchFeeModifier) -> Call {
+			Call::new(
+				Pallet::DataAvailability as u8,
+				Dispatchable::SetSubmitDataFeeModifier as u8,
+				AlreadyEncoded(modifier.encode()),
+			)
+		}
+	}
+
+	pub mod balances {
+		use super::*;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			TransferAllowDeath = 0,
+			TransferKeepAlive = 3,
+			TransferAll = 4,
+		}
+
+		/// Transfers `value` to `dest`. The sender account may be reaped if its balance drops
+		/// below the existential deposit.
+		pub fn transfer_allow_death(dest: MultiAddress, value: Balance) -> Call {
+			Call::new(
+				Pallet::Balances as u8,
+				Dispatchable::TransferAllowDeath as u8,
+				AlreadyEncoded((dest, Compact(value)).encode()),
+			)
+		}
+
+		/// Same as [`transfer_allow_death`] but fails if the sender account would be reaped.
+		pub fn transfer_keep_alive(dest: MultiAddress, value: Balance) -> Call {
+			Call::new(
+				Pallet::Balances as u8,
+				Dispatchable::TransferKeepAlive as u8,
+				AlreadyEncoded((dest, Compact(value)).encode()),
+			)
+		}
+
+		/// Transfers the whole transferable balance to `dest`. If `keep_alive` is set, the
+		/// existential deposit is left behind so the sender account is not reaped.
+		pub fn transfer_all(dest: MultiAddress, keep_alive: bool) -> Call {
+			Call::new(
+				Pallet::Balances as u8,
+				Dispatchable::TransferAll as u8,
+				AlreadyEncoded((dest, keep_alive).encode()),
+			)
+		}
+	}
+
+	pub mod utility {
+		use super::*;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			Batch = 0,
+			BatchAll = 2,
+			ForceBatch = 4,
+		}
+
+		/// Dispatches `calls` one after another. Stops at the first failing call, but calls that
+		/// were already dispatched are not reverted.
+		pub fn batch(calls: Vec<Call>) -> Call {
+			Call::new(
+				Pallet::Utility as u8,
+				Dispatchable::Batch as u8,
+				AlreadyEncoded(calls.encode()),
+			)
+		}
+
+		/// Dispatches `calls` atomically. If any of them fails, all of them are reverted.
+		pub fn batch_all(calls: Vec<Call>) -> Call {
+			Call::new(
+				Pallet::Utility as u8,
+				Dispatchable::BatchAll as u8,
+				AlreadyEncoded(calls.encode()),
+			)
+		}
+
+		/// Dispatches all `calls` even if some of them fail.
+		pub fn force_batch(calls: Vec<Call>) -> Call {
+			Call::new(
+				Pallet::Utility as u8,
+				Dispatchable::ForceBatch as u8,
+				AlreadyEncoded(calls.encode()),
+			)
+		}
+	}
+
+	pub mod staking {
+		use super::*;
+
+		/// Counter for the number of eras that have passed.
+		pub type EraIndex = u32;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			Bond = 0,
+			Unbond = 2,
+			WithdrawUnbonded = 3,
+			Nominate = 5,
+			Chill = 6,
+			PayoutStakers = 18,
+		}
+
+		/// A destination account for payment.
+		#[repr(u8)]
+		#[derive(Debug, Clone, PartialEq, Eq)]
+		pub enum RewardDestination {
+			/// Pay into the stash account, increasing the amount at stake accordingly.
+			Staked = 0,
+			/// Pay into the stash account, not increasing the amount at stake.
+			Stash = 1,
+			/// Deprecated. Pay into the controller account.
+			Controller = 2,
+			/// Pay into a specified account.
+			Account(AccountId) = 3,
+			/// Receive no reward.
+			None = 4,
+		}
+		impl Encode for RewardDestination {
+			fn size_hint(&self) -> usize {
+				match self {
+					RewardDestination::Account(x) => x.size_hint() + 1,
+					_ => 1,
+				}
+			}
+
+			fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+				match self {
+					RewardDestination::Staked => 0u8.encode_to(dest),
+					RewardDestination::Stash => 1u8.encode_to(dest),
+					RewardDestination::Controller => 2u8.encode_to(dest),
+					RewardDestination::Account(x) => {
+						3u8.encode_to(dest);
+						x.encode_to(dest);
+					},
+					RewardDestination::None => 4u8.encode_to(dest),
+				}
+			}
+		}
+
+		/// Locks up `value` of the stash account and sets where rewards are paid to.
+		pub fn bond(value: Balance, payee: RewardDestination) -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::Bond as u8,
+				AlreadyEncoded((Compact(value), payee).encode()),
+			)
+		}
+
+		/// Schedules `value` to be unlocked once the bonding duration has passed.
+		pub fn unbond(value: Balance) -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::Unbond as u8,
+				AlreadyEncoded(Compact(value).encode()),
+			)
+		}
+
+		/// Removes unlocked chunks from the ledger. `num_slashing_spans` is only needed when the
+		/// stash is fully unbonded and should be 0 otherwise.
+		pub fn withdraw_unbonded(num_slashing_spans: u32) -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::WithdrawUnbonded as u8,
+				AlreadyEncoded(num_slashing_spans.encode()),
+			)
+		}
+
+		/// Declares the desire to nominate `targets`.
+		pub fn nominate(targets: Vec<MultiAddress>) -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::Nominate as u8,
+				AlreadyEncoded(targets.encode()),
+			)
+		}
+
+		/// Declares no desire to either validate or nominate.
+		pub fn chill() -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::Chill as u8,
+				AlreadyEncoded(Vec::new()),
+			)
+		}
+
+		/// Pays out the rewards of `era` to the validator and its nominators.
+		pub fn payout_stakers(validator_stash: AccountId, era: EraIndex) -> Call {
+			Call::new(
+				Pallet::Staking as u8,
+				Dispatchable::PayoutStakers as u8,
+				AlreadyEncoded((validator_stash, era).encode()),
+			)
+		}
+	}
+
+	pub mod multisig {
+		use super::*;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			AsMulti = 1,
+			ApproveAsMulti = 2,
+			CancelAsMulti = 3,
+		}
+
+		/// A global extrinsic index, formed as the extrinsic index within a block, together with
+		/// that block's height. Used to identify an open multisig operation.
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		pub struct Timepoint {
+			/// The height of the chain at the point in time.
+			pub height: BlockNumber,
+			/// The index of the extrinsic at the point in time.
+			pub index: u32,
+		}
+		impl Timepoint {
+			pub fn new(height: BlockNumber, index: u32) -> Self {
+				Self { height, index }
+			}
+		}
+		impl Encode for Timepoint {
+			fn size_hint(&self) -> usize {
+				self.height.size_hint() + self.index.size_hint()
+			}
+
+			fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.height.encode_to(dest);
+				self.index.encode_to(dest);
+			}
+		}
+
+		/// Hash of the call, as expected by [`approve_as_multi`] and [`cancel_as_multi`].
+		pub fn call_hash(call: &Call) -> H256 {
+			H256(blake2_256(&call.encode()))
+		}
+
+		/// Approves and, if the threshold is reached, dispatches `call` from the multisig account.
+		///
+		/// `other_signatories` are all members except the caller. They are sorted before
+		/// encoding. `maybe_timepoint` must be `None` for the first approval and the timepoint of
+		/// that first approval for all following ones.
+		pub fn as_multi(
+			threshold: u16,
+			other_signatories: Vec<AccountId>,
+			maybe_timepoint: Option<Timepoint>,
+			call: Call,
+			max_weight: Weight,
+		) -> Call {
+			let other_signatories = sorted(other_signatories);
+			Call::new(
+				Pallet::Multisig as u8,
+				Dispatchable::AsMulti as u8,
+				AlreadyEncoded(
+					(
+						threshold,
+						other_signatories,
+						maybe_timepoint,
+						call,
+						max_weight,
+					)
+						.encode(),
+				),
+			)
+		}
+
+		/// Approves a multisig operation by its call hash, without dispatching it.
+		pub fn approve_as_multi(
+			threshold: u16,
+			other_signatories: Vec<AccountId>,
+			maybe_timepoint: Option<Timepoint>,
+			call_hash: H256,
+			max_weight: Weight,
+		) -> Call {
+			let other_signatories = sorted(other_signatories);
+			Call::new(
+				Pallet::Multisig as u8,
+				Dispatchable::ApproveAsMulti as u8,
+				AlreadyEncoded(
+					(
+						threshold,
+						other_signatories,
+						maybe_timepoint,
+						call_hash,
+						max_weight,
+					)
+						.encode(),
+				),
+			)
+		}
+
+		/// Cancels a multisig operation. Can only be called by the account that opened it.
+		pub fn cancel_as_multi(
+			threshold: u16,
+			other_signatories: Vec<AccountId>,
+			timepoint: Timepoint,
+			call_hash: H256,
+		) -> Call {
+			let other_signatories = sorted(other_signatories);
+			Call::new(
+				Pallet::Multisig as u8,
+				Dispatchable::CancelAsMulti as u8,
+				AlreadyEncoded((threshold, other_signatories, timepoint, call_hash).encode()),
+			)
+		}
+
+		fn sorted(mut signatories: Vec<AccountId>) -> Vec<AccountId> {
+			signatories.sort();
+			signatories
+		}
+	}
+
+	pub mod proxy {
+		use super::*;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			Proxy = 0,
+			AddProxy = 1,
+			RemoveProxy = 2,
+			CreatePure = 4,
+			KillPure = 5,
+		}
+
+		/// The type of calls a proxy account is allowed to make.
+		#[repr(u8)]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		pub enum ProxyType {
+			Any = 0,
+			NonTransfer = 1,
+			Governance = 2,
+			Staking = 3,
+			IdentityJudgement = 4,
+			NominationPools = 5,
+		}
+		impl Encode for ProxyType {
+			fn size_hint(&self) -> usize {
+				1
+			}
+
+			fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+				(*self as u8).encode_to(dest);
+			}
+		}
+
+		/// Dispatches `call` on behalf of `real`. The caller must be a proxy of `real`.
+		/// If `force_proxy_type` is set, only a proxy of that type is used.
+		pub fn proxy(real: MultiAddress, force_proxy_type: Option<ProxyType>, call: Call) -> Call {
+			Call::new(
+				Pallet::Proxy as u8,
+				Dispatchable::Proxy as u8,
+				AlreadyEncoded((real, force_proxy_type, call).encode()),
+			)
+		}
+
+		/// Registers `delegate` as a proxy of the caller. `delay` is the number of blocks an
+		/// announcement must be in place before the proxy can execute it.
+		pub fn add_proxy(
+			delegate: MultiAddress,
+			proxy_type: ProxyType,
+			delay: BlockNumber,
+		) -> Call {
+			Call::new(
+				Pallet::Proxy as u8,
+				Dispatchable::AddProxy as u8,
+				AlreadyEncoded((delegate, proxy_type, delay).encode()),
+			)
+		}
+
+		/// Unregisters a proxy of the caller. The arguments must match the ones used in
+		/// [`add_proxy`].
+		pub fn remove_proxy(
+			delegate: MultiAddress,
+			proxy_type: ProxyType,
+			delay: BlockNumber,
+		) -> Call {
+			Call::new(
+				Pallet::Proxy as u8,
+				Dispatchable::RemoveProxy as u8,
+				AlreadyEncoded((delegate, proxy_type, delay).encode()),
+			)
+		}
+
+		/// Spawns a fresh account with no private key and registers the caller as its proxy.
+		/// The new account id can be computed with [`pure_account_id`].
+		pub fn create_pure(proxy_type: ProxyType, delay: BlockNumber, index: u16) -> Call {
+			Call::new(
+				Pallet::Proxy as u8,
+				Dispatchable::CreatePure as u8,
+				AlreadyEncoded((proxy_type, delay, index).encode()),
+			)
+		}
+
+		/// Removes a pure proxy account. Must be called through [`proxy`] on behalf of the pure
+		/// account, with the same arguments that were used to create it.
+		pub fn kill_pure(
+			spawner: MultiAddress,
+			proxy_type: ProxyType,
+			index: u16,
+			height: BlockNumber,
+			ext_index: u32,
+		) -> Call {
+			Call::new(
+				Pallet::Proxy as u8,
+				Dispatchable::KillPure as u8,
+				AlreadyEncoded(
+					(
+						spawner,
+						proxy_type,
+						index,
+						Compact(height),
+						Compact(ext_index),
+					)
+						.encode(),
+				),
+			)
+		}
+
+		/// Computes the account id of a pure proxy the same way `pallet_proxy` does.
+		///
+		/// `height` and `ext_index` are the block number and extrinsic index of the
+		/// [`create_pure`] transaction.
+		pub fn pure_account_id(
+			spawner: &AccountId,
+			proxy_type: ProxyType,
+			index: u16,
+			height: BlockNumber,
+			ext_index: u32,
+		) -> AccountId {
+			let entropy = (
+				b"modlpy/proxy____",
+				spawner,
+				height,
+				ext_index,
+				proxy_type,
+				index,
+			)
+				.using_encoded(blake2_256);
+
+			AccountId(entropy)
+		}
+	}
+
+	pub mod vector {
+		use super::*;
+		use kate::{AddressedMessage, Message};
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			FulfillCall = 0,
+			Execute = 1,
+			SendMessage = 3,
+		}
+
+		/// Sends a bridge message to `to` on the `domain` chain.
+		pub fn send_message(message: Message, to: H256, domain: u32) -> Call {
+			Call::new(
+				Pallet::Vector as u8,
+				Dispatchable::SendMessage as u8,
+				AlreadyEncoded((message, to, Compact(domain)).encode()),
+			)
+		}
+
+		/// Executes a bridge message sent from the other domain. `account_proof` and
+		/// `storage_proof` prove that the message was sent in the given `slot`.
+		pub fn execute(
+			slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: Vec<Vec<u8>>,
+			storage_proof: Vec<Vec<u8>>,
+		) -> Call {
+			Call::new(
+				Pallet::Vector as u8,
+				Dispatchable::Execute as u8,
+				AlreadyEncoded(
+					(Compact(slot), addr_message, account_proof, storage_proof).encode(),
+				),
+			)
+		}
+
+		/// Submits the output of a light client update function together with its proof.
+		pub fn fulfill_call(
+			function_id: H256,
+			input: Vec<u8>,
+			output: Vec<u8>,
+			proof: Vec<u8>,
+			slot: u64,
+		) -> Call {
+			Call::new(
+				Pallet::Vector as u8,
+				Dispatchable::FulfillCall as u8,
+				AlreadyEncoded((function_id, input, output, proof, Compact(slot)).encode()),
+			)
+		}
+	}
+
+	pub mod sudo {
+		use super::*;
+
+		#[repr(u8)]
+		pub enum Dispatchable {
+			Sudo = 0,
+			SudoUncheckedWeight = 1,
+			SudoAs = 3,
+		}
+
+		/// Dispatches `call` with root origin. The caller must be the sudo key.
+		pub fn sudo(call: Call) -> Call {
+			Call::new(
+				Pallet::Sudo as u8,
+				Dispatchable::Sudo as u8,
+				AlreadyEncoded(call.encode()),
+			)
+		}
+
+		/// Same as [`sudo`] but the weight of `call` is replaced by `weight`.
+		pub fn sudo_unchecked_weight(call: Call, weight: Weight) -> Call {
+			Call::new(
+				Pallet::Sudo as u8,
+				Dispatchable::SudoUncheckedWeight as u8,
+				AlreadyEncoded((call, weight).encode()),
+			)
+		}
+
+		/// Dispatches `call` with a signed origin of `who`. The caller must be the sudo key.
+		pub fn sudo_as(who: MultiAddress, call: Call) -> Call {
+			Call::new(
+				Pallet::Sudo as u8,
+				Dispatchable::SudoAs as u8,
+				AlreadyEncoded((who, call).encode()),
+			)
+		}
 	}
 }
 
@@ -125,7 +921,7 @@ pub mod kate {
 		pub bridge_root: H256,
 	}
 
-	#[derive(Debug, Clone, Deserialize)]
+	#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 	#[serde(rename_all = "camelCase")]
 	pub struct AddressedMessage {
 		pub message: Message,
@@ -136,12 +932,89 @@ pub mod kate {
 		/// Unique identifier for the message
 		pub id: u64,
 	}
+	// Domains and id are compact encoded.
+	impl Encode for AddressedMessage {
+		fn size_hint(&self) -> usize {
+			self.message.size_hint()
+				+ self.from.size_hint()
+				+ self.to.size_hint()
+				+ Compact(self.origin_domain).size_hint()
+				+ Compact(self.destination_domain).size_hint()
+				+ Compact(self.id).size_hint()
+		}
 
-	#[derive(Debug, Clone, Deserialize)]
+		fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+			self.message.encode_to(dest);
+			self.from.encode_to(dest);
+			self.to.encode_to(dest);
+			Compact(self.origin_domain).encode_to(dest);
+			Compact(self.destination_domain).encode_to(dest);
+			Compact(self.id).encode_to(dest);
+		}
+	}
+	impl Decode for AddressedMessage {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				message: Message::decode(input)?,
+				from: H256::decode(input)?,
+				to: H256::decode(input)?,
+				origin_domain: Compact::<u32>::decode(input)?.0,
+				destination_domain: Compact::<u32>::decode(input)?.0,
+				id: Compact::<u64>::decode(input)?.0,
+			})
+		}
+	}
+
+	#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 	pub enum Message {
 		ArbitraryMessage(Vec<u8>),
 		FungibleToken { asset_id: H256, amount: u128 },
 	}
+	impl Encode for Message {
+		fn size_hint(&self) -> usize {
+			let size = match self {
+				Message::ArbitraryMessage(x) => x.size_hint(),
+				Message::FungibleToken { asset_id, amount } => {
+					asset_id.size_hint() + Compact(*amount).size_hint()
+				},
+			};
+
+			size + 1
+		}
+
+		fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+			match self {
+				Message::ArbitraryMessage(x) => {
+					0u8.encode_to(dest);
+					x.encode_to(dest);
+				},
+				Message::FungibleToken { asset_id, amount } => {
+					1u8.encode_to(dest);
+					asset_id.encode_to(dest);
+					Compact(*amount).encode_to(dest);
+				},
+			}
+		}
+	}
+	impl Decode for Message {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Message::ArbitraryMessage(Vec::<u8>::decode(input)?)),
+				1 => {
+					let asset_id = H256::decode(input)?;
+					let amount = Compact::<u128>::decode(input)?.0;
+					Ok(Message::FungibleToken { asset_id, amount })
+				},
+				_ => Err(parity_scale_codec::Error::from("Unknown Message Index")),
+			}
+		}
+	}
 
 	pub type GRawScalar = primitive_types::U256;
 	pub type GRow = Vec<GRawScalar>;
@@ -181,7 +1054,11 @@ pub mod kate {
 }
 
 pub mod block {
+	use super::calls::DecodedCall;
 	use super::*;
+	use crate::types::{
+		error::CoreError, DecodedTransaction, Era, OpaqueTransaction, TransactionSignature,
+	};
 
 	/// Consensus engine unique ID.
 	pub type ConsensusEngineId = [u8; 4];
@@ -203,6 +1080,75 @@ pub mod block {
 		#[serde(deserialize_with = "decode_extrinsics")]
 		pub extrinsics: Vec<String>,
 	}
+	impl Block {
+		/// Decodes all extrinsics of the block. Fails if any of them is not a valid extrinsic.
+		pub fn decoded_extrinsics(&self) -> Result<Vec<DecodedExtrinsic>, CoreError> {
+			self.extrinsics
+				.iter()
+				.map(|x| DecodedExtrinsic::from_hex_string(x))
+				.collect()
+		}
+
+		/// Decodes the extrinsic at `index`. Returns `None` if there is no such extrinsic.
+		pub fn decoded_extrinsic(
+			&self,
+			index: usize,
+		) -> Option<Result<DecodedExtrinsic, CoreError>> {
+			self.extrinsics
+				.get(index)
+				.map(|x| DecodedExtrinsic::from_hex_string(x))
+		}
+	}
+
+	/// Typed view of an extrinsic found in a block.
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	pub struct DecodedExtrinsic {
+		/// Same as [`OpaqueTransaction::get_hash`].
+		pub hash: H256,
+		/// `None` for unsigned extrinsics and inherents.
+		pub signature: Option<TransactionSignature>,
+		pub pallet_index: u8,
+		pub call_index: u8,
+		pub call: DecodedCall,
+	}
+	impl DecodedExtrinsic {
+		pub fn from_hex_string(s: &str) -> Result<Self, CoreError> {
+			let transaction = OpaqueTransaction::from_hex_string(s)?;
+			let DecodedTransaction { signature, call } = transaction.decoded()?;
+
+			Ok(Self {
+				hash: transaction.get_hash(),
+				signature,
+				pallet_index: call.pallet_index,
+				call_index: call.call_index,
+				call: DecodedCall::from_call(call),
+			})
+		}
+
+		pub fn is_signed(&self) -> bool {
+			self.signature.is_some()
+		}
+
+		pub fn signer(&self) -> Option<&MultiAddress> {
+			self.signature.as_ref().map(|x| &x.address)
+		}
+
+		pub fn era(&self) -> Option<&Era> {
+			self.signature.as_ref().map(|x| &x.extra.mortality)
+		}
+
+		pub fn nonce(&self) -> Option<Nonce> {
+			self.signature.as_ref().map(|x| x.extra.nonce.0)
+		}
+
+		pub fn tip(&self) -> Option<Tip> {
+			self.signature.as_ref().map(|x| x.extra.tip.0)
+		}
+
+		pub fn app_id(&self) -> Option<AppId> {
+			self.signature.as_ref().map(|x| x.extra.app_id.0)
+		}
+	}
 
 	fn decode_extrinsics<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
 	where
@@ -232,7 +1178,7 @@ pub mod block {
 	{
 		let buf = String::deserialize(deserializer)?;
 		let without_prefix = buf.trim_start_matches("0x");
-		Ok(u32::from_str_radix(without_prefix, 16).unwrap())
+		u32::from_str_radix(without_prefix, 16).map_err(serde::de::Error::custom)
 	}
 
 	#[derive(Debug, Clone, Deserialize)]
@@ -267,7 +1213,7 @@ pub mod block {
 
 			match self {
 				DigestItem::PreRuntime(x, y) => {
-					let name = String::from_utf8(x.to_vec()).unwrap();
+					let name = String::from_utf8_lossy(x);
 					let value = std::format!("0x{}", hex::encode(y));
 					result.push_str(&std::format!(
 						"DigestItem::PreRuntime [ {} {:?} ]",
@@ -276,7 +1222,7 @@ pub mod block {
 					));
 				},
 				DigestItem::Consensus(x, y) => {
-					let name = String::from_utf8(x.to_vec()).unwrap();
+					let name = String::from_utf8_lossy(x);
 					let value = std::format!("0x{}", hex::encode(y));
 					result.push_str(&std::format!(
 						"DigestItem::Consensus [ {} {:?} ]",
@@ -285,7 +1231,7 @@ pub mod block {
 					));
 				},
 				DigestItem::Seal(x, y) => {
-					let name = String::from_utf8(x.to_vec()).unwrap();
+					let name = String::from_utf8_lossy(x);
 					let value = std::format!("0x{}", hex::encode(y));
 					result.push_str(&std::format!("DigestItem::Seal [ {} {:?} ]", name, value));
 				},
@@ -311,9 +1257,9 @@ pub mod block {
 		let mut items: Vec<DigestItem> = Vec::with_capacity(buf.len());
 		for encoded_item in buf {
 			let encoded_item = encoded_item.trim_start_matches("0x");
-			let hex_decoded_item = hex::decode(encoded_item).unwrap();
+			let hex_decoded_item = hex::decode(encoded_item).map_err(serde::de::Error::custom)?;
 			let mut bytes = hex_decoded_item.as_slice();
-			let item = DigestItem::decode(&mut bytes).unwrap();
+			let item = DigestItem::decode(&mut bytes).map_err(serde::de::Error::custom)?;
 			items.push(item);
 		}
 
@@ -481,3 +1427,638 @@ pub mod events {
 		pub changes: Vec<Vec<String>>,
 	}
 }
+
+pub mod transaction_payment {
+	use super::*;
+
+	/// A generalized group of dispatch types.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum DispatchClass {
+		/// A normal dispatch.
+		Normal,
+		/// An operational dispatch.
+		Operational,
+		/// A mandatory dispatch. These are always included regardless of their weight.
+		Mandatory,
+	}
+	impl Decode for DispatchClass {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::Normal),
+				1 => Ok(Self::Operational),
+				2 => Ok(Self::Mandatory),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown DispatchClass Index",
+				)),
+			}
+		}
+	}
+
+	/// Information related to a dispatchable's class, weight, and fee that can be queried from
+	/// the runtime.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub struct RuntimeDispatchInfo {
+		/// Weight of this dispatch.
+		pub weight: Weight,
+		/// Class of this dispatch.
+		pub class: DispatchClass,
+		/// The inclusion fee of this dispatch. Does not include the tip.
+		pub partial_fee: Balance,
+	}
+	impl Decode for RuntimeDispatchInfo {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				weight: Weight::decode(input)?,
+				class: DispatchClass::decode(input)?,
+				partial_fee: Balance::decode(input)?,
+			})
+		}
+	}
+
+	/// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
+	#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+	pub struct InclusionFee {
+		/// Minimum amount a user pays for a transaction.
+		pub base_fee: Balance,
+		/// Amount paid for the encoded length (in bytes) of the transaction. For `submit_data`
+		/// this is where the data availability fee ends up.
+		pub len_fee: Balance,
+		/// The weight fee multiplied by the fee multiplier.
+		pub adjusted_weight_fee: Balance,
+	}
+	impl InclusionFee {
+		pub fn inclusion_fee(&self) -> Balance {
+			self.base_fee
+				.saturating_add(self.len_fee)
+				.saturating_add(self.adjusted_weight_fee)
+		}
+	}
+	impl Decode for InclusionFee {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				base_fee: Balance::decode(input)?,
+				len_fee: Balance::decode(input)?,
+				adjusted_weight_fee: Balance::decode(input)?,
+			})
+		}
+	}
+
+	/// The fee details of a transaction.
+	#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+	pub struct FeeDetails {
+		/// `None` for unsigned transactions, which pay no inclusion fee.
+		pub inclusion_fee: Option<InclusionFee>,
+		pub tip: Balance,
+	}
+	impl FeeDetails {
+		/// Inclusion fee plus tip.
+		pub fn final_fee(&self) -> Balance {
+			self.inclusion_fee
+				.map(|x| x.inclusion_fee())
+				.unwrap_or(0)
+				.saturating_add(self.tip)
+		}
+	}
+	impl Decode for FeeDetails {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				inclusion_fee: Option::<InclusionFee>::decode(input)?,
+				tip: Balance::decode(input)?,
+			})
+		}
+	}
+}
+
+pub mod transaction_validity {
+	use super::*;
+
+	/// The source of the transaction.
+	#[repr(u8)]
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum TransactionSource {
+		/// Transaction is already included in block.
+		InBlock = 0,
+		/// Transaction is coming from a local source, e.g. an offchain worker.
+		Local = 1,
+		/// Transaction has been received externally, e.g. over the RPC.
+		External = 2,
+	}
+	impl Encode for TransactionSource {
+		fn size_hint(&self) -> usize {
+			1
+		}
+
+		fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+			(*self as u8).encode_to(dest);
+		}
+	}
+
+	/// Information on a transaction's validity and, if valid, on how it relates to other
+	/// transactions.
+	pub type TransactionValidity = Result<ValidTransaction, TransactionValidityError>;
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	pub struct ValidTransaction {
+		/// Priority of the transaction. Higher priority transactions are included first.
+		pub priority: u64,
+		/// Tags that have to be provided by other transactions before this one can be included,
+		/// e.g. the previous nonce of the same account.
+		pub requires: Vec<Vec<u8>>,
+		/// Tags provided by this transaction.
+		pub provides: Vec<Vec<u8>>,
+		/// Number of blocks for which the transaction stays valid.
+		pub longevity: u64,
+		/// Whether the transaction should be gossiped to other peers.
+		pub propagate: bool,
+	}
+	impl Decode for ValidTransaction {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				priority: u64::decode(input)?,
+				requires: Vec::<Vec<u8>>::decode(input)?,
+				provides: Vec::<Vec<u8>>::decode(input)?,
+				longevity: u64::decode(input)?,
+				propagate: bool::decode(input)?,
+			})
+		}
+	}
+
+	/// Errors that can occur while checking the validity of a transaction.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum TransactionValidityError {
+		/// The transaction is invalid.
+		Invalid(InvalidTransaction),
+		/// Transaction validity can't be determined.
+		Unknown(UnknownTransaction),
+	}
+	impl Decode for TransactionValidityError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::Invalid(InvalidTransaction::decode(input)?)),
+				1 => Ok(Self::Unknown(UnknownTransaction::decode(input)?)),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown TransactionValidityError Index",
+				)),
+			}
+		}
+	}
+
+	/// An invalid transaction validity.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum InvalidTransaction {
+		/// The call of the transaction is not expected.
+		Call,
+		/// General error to do with the inability to pay some fees (e.g. account balance too
+		/// low).
+		Payment,
+		/// General error to do with the transaction not yet being valid (e.g. nonce too high).
+		Future,
+		/// General error to do with the transaction being outdated (e.g. nonce too low).
+		Stale,
+		/// General error to do with the transaction's proofs (e.g. signature).
+		BadProof,
+		/// The transaction birth block is ancient.
+		AncientBirthBlock,
+		/// The transaction would exhaust the resources of current block.
+		ExhaustsResources,
+		/// Any other custom invalid validity that is not covered by this enum.
+		Custom(u8),
+		/// An extrinsic with a Mandatory dispatch resulted in Error.
+		BadMandatory,
+		/// An extrinsic with a mandatory dispatch tried to be validated.
+		MandatoryValidation,
+		/// The sending address is disabled or known to be invalid.
+		BadSigner,
+	}
+	impl Decode for InvalidTransaction {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::Call),
+				1 => Ok(Self::Payment),
+				2 => Ok(Self::Future),
+				3 => Ok(Self::Stale),
+				4 => Ok(Self::BadProof),
+				5 => Ok(Self::AncientBirthBlock),
+				6 => Ok(Self::ExhaustsResources),
+				7 => Ok(Self::Custom(u8::decode(input)?)),
+				8 => Ok(Self::BadMandatory),
+				9 => Ok(Self::MandatoryValidation),
+				10 => Ok(Self::BadSigner),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown InvalidTransaction Index",
+				)),
+			}
+		}
+	}
+
+	/// An unknown transaction validity.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum UnknownTransaction {
+		/// Could not lookup some information that is required to validate the transaction.
+		CannotLookup,
+		/// No validator found for the given unsigned transaction.
+		NoUnsignedValidator,
+		/// Any other custom unknown validity that is not covered by this enum.
+		Custom(u8),
+	}
+	impl Decode for UnknownTransaction {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::CannotLookup),
+				1 => Ok(Self::NoUnsignedValidator),
+				2 => Ok(Self::Custom(u8::decode(input)?)),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown UnknownTransaction Index",
+				)),
+			}
+		}
+	}
+}
+
+pub mod dispatch {
+	use super::transaction_validity::TransactionValidityError;
+	use super::*;
+
+	/// Result of applying an extrinsic. The outer error means the extrinsic could not be
+	/// included, the inner one that it was included but its call failed.
+	pub type ApplyExtrinsicResult = Result<DispatchOutcome, TransactionValidityError>;
+	/// Result of dispatching a call.
+	pub type DispatchOutcome = Result<(), DispatchError>;
+
+	/// Reason why a dispatch call failed.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum DispatchError {
+		/// Some error occurred.
+		Other,
+		/// Failed to lookup some data.
+		CannotLookup,
+		/// A bad origin.
+		BadOrigin,
+		/// A custom error in a module.
+		Module(ModuleError),
+		/// At least one consumer is remaining so the account cannot be destroyed.
+		ConsumerRemaining,
+		/// There are no providers so the account cannot be created.
+		NoProviders,
+		/// There are too many consumers so the account cannot be created.
+		TooManyConsumers,
+		/// An error to do with tokens.
+		Token(TokenError),
+		/// An arithmetic error.
+		Arithmetic(ArithmeticError),
+		/// The number of transactional layers has been reached, or we are not in a transactional
+		/// layer.
+		Transactional(TransactionalError),
+		/// Resources exhausted, e.g. attempt to read/write data which is too large to manipulate.
+		Exhausted,
+		/// The state is corrupt; this is generally not going to fix itself.
+		Corruption,
+		/// Some resource (e.g. a preimage) is unavailable right now. This might fix itself later.
+		Unavailable,
+		/// Root origin is not allowed.
+		RootNotAllowed,
+	}
+	impl Decode for DispatchError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::Other),
+				1 => Ok(Self::CannotLookup),
+				2 => Ok(Self::BadOrigin),
+				3 => Ok(Self::Module(ModuleError::decode(input)?)),
+				4 => Ok(Self::ConsumerRemaining),
+				5 => Ok(Self::NoProviders),
+				6 => Ok(Self::TooManyConsumers),
+				7 => Ok(Self::Token(TokenError::decode(input)?)),
+				8 => Ok(Self::Arithmetic(ArithmeticError::decode(input)?)),
+				9 => Ok(Self::Transactional(TransactionalError::decode(input)?)),
+				10 => Ok(Self::Exhausted),
+				11 => Ok(Self::Corruption),
+				12 => Ok(Self::Unavailable),
+				13 => Ok(Self::RootNotAllowed),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown DispatchError Index",
+				)),
+			}
+		}
+	}
+
+	/// Reason why a pallet call failed. Turning it into the error name requires the metadata.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub struct ModuleError {
+		/// Pallet index.
+		pub index: u8,
+		/// Encoded error. The first byte is the index of the error variant.
+		pub error: [u8; 4],
+	}
+	impl Decode for ModuleError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			Ok(Self {
+				index: u8::decode(input)?,
+				error: <[u8; 4]>::decode(input)?,
+			})
+		}
+	}
+
+	/// Description of what went wrong when trying to complete an operation on a token.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum TokenError {
+		/// Funds are unavailable.
+		FundsUnavailable,
+		/// Some part of the balance gives the only provider reference to the account and thus
+		/// cannot be (re)moved.
+		OnlyProvider,
+		/// Account cannot exist with the funds that would be given.
+		BelowMinimum,
+		/// Account cannot be created.
+		CannotCreate,
+		/// The asset in question is unknown.
+		UnknownAsset,
+		/// Funds exist but are frozen.
+		Frozen,
+		/// Operation is not supported by the asset.
+		Unsupported,
+		/// Account cannot be created for a held balance.
+		CannotCreateHold,
+		/// Withdrawal would cause unwanted loss of account.
+		NotExpendable,
+		/// Account cannot receive the assets.
+		Blocked,
+	}
+	impl Decode for TokenError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::FundsUnavailable),
+				1 => Ok(Self::OnlyProvider),
+				2 => Ok(Self::BelowMinimum),
+				3 => Ok(Self::CannotCreate),
+				4 => Ok(Self::UnknownAsset),
+				5 => Ok(Self::Frozen),
+				6 => Ok(Self::Unsupported),
+				7 => Ok(Self::CannotCreateHold),
+				8 => Ok(Self::NotExpendable),
+				9 => Ok(Self::Blocked),
+				_ => Err(parity_scale_codec::Error::from("Unknown TokenError Index")),
+			}
+		}
+	}
+
+	/// Arithmetic errors.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum ArithmeticError {
+		/// Underflow.
+		Underflow,
+		/// Overflow.
+		Overflow,
+		/// Division by zero.
+		DivisionByZero,
+	}
+	impl Decode for ArithmeticError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::Underflow),
+				1 => Ok(Self::Overflow),
+				2 => Ok(Self::DivisionByZero),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown ArithmeticError Index",
+				)),
+			}
+		}
+	}
+
+	/// Errors related to transactional storage layers.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum TransactionalError {
+		/// Too many transactional layers have been spawned.
+		LimitReached,
+		/// A transactional layer was expected, but does not exist.
+		NoLayer,
+	}
+	impl Decode for TransactionalError {
+		fn decode<I: parity_scale_codec::Input>(
+			input: &mut I,
+		) -> Result<Self, parity_scale_codec::Error> {
+			let index: u8 = u8::decode(input)?;
+
+			match index {
+				0 => Ok(Self::LimitReached),
+				1 => Ok(Self::NoLayer),
+				_ => Err(parity_scale_codec::Error::from(
+					"Unknown TransactionalError Index",
+				)),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::dispatch::*;
+	use super::transaction_payment::*;
+	use super::transaction_validity::*;
+	use super::{TransactionStatus, Weight, H256};
+	use parity_scale_codec::{Compact, DecodeAll, Encode};
+
+	#[test]
+	fn runtime_dispatch_info_decode() {
+		let encoded = (Compact(100u64), Compact(200u64), 1u8, 5u128).encode();
+		let expected = RuntimeDispatchInfo {
+			weight: Weight::new(100, 200),
+			class: DispatchClass::Operational,
+			partial_fee: 5,
+		};
+		let decoded = RuntimeDispatchInfo::decode_all(&mut encoded.as_slice()).unwrap();
+		assert_eq!(decoded, expected);
+	}
+
+	#[test]
+	fn fee_details_decode() {
+		let encoded = (1u8, 1u128, 2u128, 3u128, 4u128).encode();
+		let decoded = FeeDetails::decode_all(&mut encoded.as_slice()).unwrap();
+		let fee = InclusionFee {
+			base_fee: 1,
+			len_fee: 2,
+			adjusted_weight_fee: 3,
+		};
+		assert_eq!(decoded.inclusion_fee, Some(fee));
+		assert_eq!(decoded.tip, 4);
+		assert_eq!(decoded.final_fee(), 10);
+
+		let encoded = (0u8, 0u128).encode();
+		let decoded = FeeDetails::decode_all(&mut encoded.as_slice()).unwrap();
+		assert_eq!(decoded, FeeDetails::default());
+	}
+
+	#[test]
+	fn transaction_validity_decode() {
+		let valid = (
+			0u8,
+			10u64,
+			Vec::<Vec<u8>>::new(),
+			vec![vec![1u8, 2]],
+			64u64,
+			true,
+		)
+			.encode();
+		let expected = ValidTransaction {
+			priority: 10,
+			requires: vec![],
+			provides: vec![vec![1, 2]],
+			longevity: 64,
+			propagate: true,
+		};
+		let decoded = TransactionValidity::decode_all(&mut valid.as_slice()).unwrap();
+		assert_eq!(decoded, Ok(expected));
+
+		let cases = [
+			(
+				vec![1u8, 0, 3],
+				TransactionValidityError::Invalid(InvalidTransaction::Stale),
+			),
+			(
+				vec![1, 0, 7, 9],
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(9)),
+			),
+			(
+				vec![1, 1, 0],
+				TransactionValidityError::Unknown(UnknownTransaction::CannotLookup),
+			),
+			(
+				vec![1, 1, 2, 5],
+				TransactionValidityError::Unknown(UnknownTransaction::Custom(5)),
+			),
+		];
+		for (encoded, expected) in cases {
+			let decoded = TransactionValidity::decode_all(&mut encoded.as_slice()).unwrap();
+			assert_eq!(decoded, Err(expected));
+		}
+
+		assert!(TransactionValidity::decode_all(&mut [1u8, 0, 11].as_slice()).is_err());
+	}
+
+	#[test]
+	fn apply_extrinsic_result_decode() {
+		let module = ModuleError {
+			index: 29,
+			error: [1, 0, 0, 0],
+		};
+		let cases = [
+			(vec![0u8, 0], Ok(Ok(()))),
+			(
+				vec![0, 1, 3, 29, 1, 0, 0, 0],
+				Ok(Err(DispatchError::Module(module))),
+			),
+			(
+				vec![0, 1, 8, 1],
+				Ok(Err(DispatchError::Arithmetic(ArithmeticError::Overflow))),
+			),
+			(
+				vec![1, 0, 1],
+				Err(TransactionValidityError::Invalid(
+					InvalidTransaction::Payment,
+				)),
+			),
+		];
+		for (encoded, expected) in cases {
+			let decoded = ApplyExtrinsicResult::decode_all(&mut encoded.as_slice()).unwrap();
+			assert_eq!(decoded, expected);
+		}
+
+		assert!(ApplyExtrinsicResult::decode_all(&mut [0u8, 1, 14].as_slice()).is_err());
+	}
+
+	#[test]
+	fn transaction_status_from_node_json() {
+		let hash = H256([0xab; 32]);
+		let hex = hash.to_hex_string();
+		let cases = [
+			(r#""future""#.to_string(), TransactionStatus::Future),
+			(r#""ready""#.to_string(), TransactionStatus::Ready),
+			(
+				r#"{"broadcast":["12D3KooW"]}"#.to_string(),
+				TransactionStatus::Broadcast(vec!["12D3KooW".to_string()]),
+			),
+			(
+				std::format!(r#"{{"inBlock":"{hex}"}}"#),
+				TransactionStatus::InBlock(hash),
+			),
+			(
+				std::format!(r#"{{"retracted":"{hex}"}}"#),
+				TransactionStatus::Retracted(hash),
+			),
+			(
+				std::format!(r#"{{"finalityTimeout":"{hex}"}}"#),
+				TransactionStatus::FinalityTimeout(hash),
+			),
+			(
+				std::format!(r#"{{"finalized":"{hex}"}}"#),
+				TransactionStatus::Finalized(hash),
+			),
+			(
+				std::format!(r#"{{"usurped":"{hex}"}}"#),
+				TransactionStatus::Usurped(hash),
+			),
+			(r#""dropped""#.to_string(), TransactionStatus::Dropped),
+			(r#""invalid""#.to_string(), TransactionStatus::Invalid),
+		];
+
+		for (json, expected) in cases {
+			let status: TransactionStatus = serde_json::from_str(&json).unwrap();
+			assert_eq!(status, expected, "{json}");
+		}
+	}
+
+	#[test]
+	fn transaction_status_is_final() {
+		let hash = H256([0xab; 32]);
+		assert!(!TransactionStatus::Ready.is_final());
+		assert!(!TransactionStatus::InBlock(hash).is_final());
+		assert!(!TransactionStatus::Retracted(hash).is_final());
+		assert!(TransactionStatus::Finalized(hash).is_final());
+		assert!(TransactionStatus::Dropped.is_final());
+		assert!(TransactionStatus::Invalid.is_final());
+	}
+
+	#[test]
+	fn unknown_transaction_status_is_rejected() {
+		assert!(serde_json::from_str::<TransactionStatus>(r#""pending""#).is_err());
+		assert!(serde_json::from_str::<TransactionStatus>(r#"{"inBlock":"0x12"}"#).is_err());
+	}
+}
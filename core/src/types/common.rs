@@ -1,11 +1,11 @@
 use super::error::CoreError;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 /*
 	This object guarantees that an already encoded data is not encoded twice.
 */
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct AlreadyEncoded(pub Vec<u8>);
 impl AlreadyEncoded {
 	pub fn to_hex_string(&self) -> String {
@@ -21,6 +21,20 @@ impl Encode for AlreadyEncoded {
 		self.0.clone()
 	}
 }
+// Already encoded data has no length prefix so decoding it consumes the rest of the input.
+impl Decode for AlreadyEncoded {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let length = input
+			.remaining_len()?
+			.ok_or("Cannot determine the length of already encoded data")?;
+		let mut value = vec![0u8; length];
+		input.read(&mut value)?;
+
+		Ok(Self(value))
+	}
+}
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct H256(pub [u8; 32]);
@@ -29,10 +43,8 @@ impl H256 {
 		std::format!("0x{}", hex::encode(self.0))
 	}
 
-	pub fn from_hex_string(mut s: &str) -> Result<Self, CoreError> {
-		if s.starts_with("0x") {
-			s = &s[2..];
-		}
+	pub fn from_hex_string(s: &str) -> Result<Self, CoreError> {
+		let s = s.strip_prefix("0x").unwrap_or(s);
 
 		if s.len() != 64 {
 			let msg = std::format!(
@@ -64,6 +76,13 @@ impl Encode for H256 {
 		self.0.encode_to(dest);
 	}
 }
+impl Decode for H256 {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		Ok(Self(<[u8; 32]>::decode(input)?))
+	}
+}
 // When we receive block hash from RPC calls it's always in Hex like String format.
 // Example: "0x26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7"
 //
@@ -73,6 +92,6 @@ impl<'de> Deserialize<'de> for H256 {
 		D: serde::Deserializer<'de>,
 	{
 		let buf = String::deserialize(deserializer)?;
-		Ok(H256::from_hex_string(&buf).unwrap())
+		H256::from_hex_string(&buf).map_err(serde::de::Error::custom)
 	}
 }
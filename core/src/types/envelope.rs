@@ -0,0 +1,342 @@
+use super::{
+	avail::calls::DecodedCall,
+	error::CoreError,
+	multi::{MultiAddress, MultiSignature},
+	payload_fields::Era,
+	AlreadyEncoded, OpaqueTransaction, UnsignedEncodedPayload, UnsignedPayload, H256,
+};
+use crate::crypto::{blake2_256, sign_checked, Signer};
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// An [`UnsignedEncodedPayload`] together with a human readable view of it, so it can be moved
+/// to a machine that only signs.
+///
+/// The readable fields are informational. [`PayloadEnvelope::validate`] checks that they match
+/// the encoded payload, which is the only part that ends up being signed. `call_description` is
+/// not checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayloadEnvelope {
+	#[serde(with = "scale_hex")]
+	pub call: AlreadyEncoded,
+	#[serde(with = "scale_hex")]
+	pub extra: AlreadyEncoded,
+	#[serde(with = "scale_hex")]
+	pub additional: AlreadyEncoded,
+	pub call_description: String,
+	pub nonce: u32,
+	pub era: Era,
+	pub tip: u128,
+	pub app_id: u32,
+	#[serde(with = "scale_hex")]
+	pub genesis_hash: H256,
+	pub spec_version: u32,
+	pub tx_version: u32,
+}
+impl PayloadEnvelope {
+	pub fn new(payload: &UnsignedEncodedPayload) -> Result<Self, CoreError> {
+		let UnsignedPayload {
+			call,
+			extra,
+			additional,
+		} = payload.decode()?;
+
+		Ok(Self {
+			call: payload.call.clone(),
+			extra: payload.extra.clone(),
+			additional: payload.additional.clone(),
+			call_description: DecodedCall::from_call(call).to_string(),
+			nonce: extra.nonce.0,
+			era: extra.mortality,
+			tip: extra.tip.0,
+			app_id: extra.app_id.0,
+			genesis_hash: additional.genesis_hash,
+			spec_version: additional.spec_version,
+			tx_version: additional.tx_version,
+		})
+	}
+
+	pub fn payload(&self) -> UnsignedEncodedPayload {
+		UnsignedEncodedPayload::new(
+			self.call.clone(),
+			self.extra.clone(),
+			self.additional.clone(),
+		)
+	}
+
+	/// Checks that the envelope was built for the chain with `genesis_hash` and that the readable
+	/// fields describe the encoded payload.
+	pub fn validate(&self, genesis_hash: H256) -> Result<UnsignedEncodedPayload, CoreError> {
+		if self.genesis_hash != genesis_hash {
+			let msg = std::format!(
+				"Envelope is for genesis hash {}, expected {}",
+				self.genesis_hash.to_hex_string(),
+				genesis_hash.to_hex_string()
+			);
+			return Err(CoreError::InvalidEnvelope(msg));
+		}
+
+		// `call_description` is not compared. Its wording may change between versions of this
+		// crate and it is never signed.
+		let payload = self.payload();
+		let UnsignedPayload {
+			call,
+			extra,
+			additional,
+		} = payload.decode()?;
+		let matches = call.encode() == self.call.0
+			&& extra.encode() == self.extra.0
+			&& additional.encode() == self.additional.0
+			&& extra.nonce.0 == self.nonce
+			&& extra.mortality == self.era
+			&& extra.tip.0 == self.tip
+			&& extra.app_id.0 == self.app_id
+			&& additional.genesis_hash == self.genesis_hash
+			&& additional.spec_version == self.spec_version
+			&& additional.tx_version == self.tx_version;
+		if !matches {
+			let msg = "Envelope fields do not match the encoded payload".to_string();
+			return Err(CoreError::InvalidEnvelope(msg));
+		}
+
+		Ok(payload)
+	}
+
+	/// Validates the envelope and signs the payload with `signer`.
+	pub fn sign<S: Signer + ?Sized>(
+		&self,
+		signer: &S,
+		genesis_hash: H256,
+	) -> Result<SignatureEnvelope, CoreError> {
+		let payload = self.validate(genesis_hash)?;
+
+		Ok(SignatureEnvelope {
+			payload_hash: self.payload_hash(),
+			address: signer.address(),
+			signature: sign_checked(signer, &payload)?,
+		})
+	}
+
+	/// Hash of the signing bytes. Used to tie a [`SignatureEnvelope`] to this envelope.
+	pub fn payload_hash(&self) -> H256 {
+		H256(self.payload().using_signing_bytes(blake2_256))
+	}
+
+	/// Validates the envelope and joins it with `signature` into a transaction. Fails unless the
+	/// signature was made over this payload by the account in `signature.address`.
+	pub fn into_transaction(
+		&self,
+		signature: &SignatureEnvelope,
+		genesis_hash: H256,
+	) -> Result<OpaqueTransaction, CoreError> {
+		let payload = self.validate(genesis_hash)?;
+		if signature.payload_hash != self.payload_hash() {
+			let msg = "Signature envelope was created for a different payload".to_string();
+			return Err(CoreError::InvalidEnvelope(msg));
+		}
+
+		let MultiAddress::Id(account_id) = &signature.address else {
+			let msg = "Signature envelope address is not an account id".to_string();
+			return Err(CoreError::InvalidEnvelope(msg));
+		};
+		let valid = payload.using_signing_bytes(|x| signature.signature.verify(x, account_id));
+		if !valid {
+			let msg = std::format!(
+				"{:?} signature does not belong to the signature envelope address",
+				signature.signature.kind()
+			);
+			return Err(CoreError::InvalidEnvelope(msg));
+		}
+
+		Ok(OpaqueTransaction::new(
+			&payload.extra,
+			&payload.call,
+			signature.address.clone(),
+			signature.signature.clone(),
+		))
+	}
+}
+// The encoded parts are length prefixed so they can be told apart.
+impl Encode for PayloadEnvelope {
+	fn size_hint(&self) -> usize {
+		self.call.0.size_hint()
+			+ self.extra.0.size_hint()
+			+ self.additional.0.size_hint()
+			+ self.call_description.size_hint()
+			+ self.nonce.size_hint()
+			+ self.era.size_hint()
+			+ self.tip.size_hint()
+			+ self.app_id.size_hint()
+			+ self.genesis_hash.size_hint()
+			+ self.spec_version.size_hint()
+			+ self.tx_version.size_hint()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.call.0.encode_to(dest);
+		self.extra.0.encode_to(dest);
+		self.additional.0.encode_to(dest);
+		self.call_description.encode_to(dest);
+		self.nonce.encode_to(dest);
+		self.era.encode_to(dest);
+		self.tip.encode_to(dest);
+		self.app_id.encode_to(dest);
+		self.genesis_hash.encode_to(dest);
+		self.spec_version.encode_to(dest);
+		self.tx_version.encode_to(dest);
+	}
+}
+impl Decode for PayloadEnvelope {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		Ok(Self {
+			call: AlreadyEncoded(Vec::<u8>::decode(input)?),
+			extra: AlreadyEncoded(Vec::<u8>::decode(input)?),
+			additional: AlreadyEncoded(Vec::<u8>::decode(input)?),
+			call_description: String::decode(input)?,
+			nonce: u32::decode(input)?,
+			era: Era::decode(input)?,
+			tip: u128::decode(input)?,
+			app_id: u32::decode(input)?,
+			genesis_hash: H256::decode(input)?,
+			spec_version: u32::decode(input)?,
+			tx_version: u32::decode(input)?,
+		})
+	}
+}
+
+/// The signature for a [`PayloadEnvelope`], carried back from the signing machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureEnvelope {
+	/// See [`PayloadEnvelope::payload_hash`].
+	#[serde(with = "scale_hex")]
+	pub payload_hash: H256,
+	#[serde(with = "scale_hex")]
+	pub address: MultiAddress,
+	#[serde(with = "scale_hex")]
+	pub signature: MultiSignature,
+}
+impl Encode for SignatureEnvelope {
+	fn size_hint(&self) -> usize {
+		self.payload_hash.size_hint() + self.address.size_hint() + self.signature.size_hint()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.payload_hash.encode_to(dest);
+		self.address.encode_to(dest);
+		self.signature.encode_to(dest);
+	}
+}
+impl Decode for SignatureEnvelope {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		Ok(Self {
+			payload_hash: H256::decode(input)?,
+			address: MultiAddress::decode(input)?,
+			signature: MultiSignature::decode(input)?,
+		})
+	}
+}
+
+// (De)serializes SCALE values as `0x` prefixed hex strings.
+mod scale_hex {
+	use parity_scale_codec::{DecodeAll, Encode};
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<T: Encode, S: Serializer>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		let s = std::format!("0x{}", hex::encode(value.encode()));
+		serializer.serialize_str(&s)
+	}
+
+	pub fn deserialize<'de, T: DecodeAll, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		let bytes = hex::decode(s.trim_start_matches("0x")).map_err(D::Error::custom)?;
+		T::decode_all(&mut bytes.as_slice()).map_err(D::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		crypto::{ecdsa, ed25519, Keypair, SecretUri},
+		types::{avail::DataAvailabilityCalls, Additional, Extra},
+	};
+	use parity_scale_codec::Compact;
+	use std::str::FromStr;
+
+	const GENESIS_HASH: H256 = H256([1u8; 32]);
+
+	fn envelope() -> PayloadEnvelope {
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra {
+			mortality: Era::mortal(32, 1_000),
+			nonce: Compact(7),
+			tip: Compact(0),
+			app_id: Compact(3),
+		};
+		let additional = Additional::new(39, 1, GENESIS_HASH, H256([2u8; 32]));
+		let payload = UnsignedPayload::new(call, extra, additional).encode();
+
+		PayloadEnvelope::new(&payload).unwrap()
+	}
+
+	fn uri(s: &str) -> SecretUri {
+		SecretUri::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn call_description_is_not_validated() {
+		let mut envelope = envelope();
+		envelope.call_description = "Described differently".to_string();
+		assert!(envelope.validate(GENESIS_HASH).is_ok());
+
+		envelope.nonce += 1;
+		assert!(envelope.validate(GENESIS_HASH).is_err());
+	}
+
+	#[test]
+	fn into_transaction_checks_signature() {
+		let envelope = envelope();
+		let alice = Keypair::from_uri(&uri("//Alice")).unwrap();
+		let bob = Keypair::from_uri(&uri("//Bob")).unwrap();
+
+		let signature = envelope.sign(&alice, GENESIS_HASH).unwrap();
+		assert!(envelope.into_transaction(&signature, GENESIS_HASH).is_ok());
+
+		let mut other_address = signature.clone();
+		other_address.address = MultiAddress::Id(bob.account_id());
+		assert!(envelope
+			.into_transaction(&other_address, GENESIS_HASH)
+			.is_err());
+
+		let mut other_kind = signature.clone();
+		let MultiSignature::Sr25519(bytes) = signature.signature else {
+			unreachable!()
+		};
+		other_kind.signature = MultiSignature::Ed25519(bytes);
+		assert!(envelope
+			.into_transaction(&other_kind, GENESIS_HASH)
+			.is_err());
+	}
+
+	#[test]
+	fn into_transaction_accepts_every_signature_kind() {
+		let envelope = envelope();
+
+		let signer = ed25519::Keypair::from_uri(&uri("//Alice")).unwrap();
+		let signature = envelope.sign(&signer, GENESIS_HASH).unwrap();
+		assert!(envelope.into_transaction(&signature, GENESIS_HASH).is_ok());
+
+		let signer = ecdsa::Keypair::from_uri(&uri("//Alice")).unwrap();
+		let signature = envelope.sign(&signer, GENESIS_HASH).unwrap();
+		assert!(envelope.into_transaction(&signature, GENESIS_HASH).is_ok());
+	}
+}
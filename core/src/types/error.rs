@@ -1,5 +1,38 @@
+use std::fmt::Display;
+
 #[derive(Debug, Clone)]
 pub enum CoreError {
 	FromHexError(hex::FromHexError),
 	ConversionError(String),
+	CodecError(parity_scale_codec::Error),
+	/// A payload or signature envelope does not match what it is being used with.
+	InvalidEnvelope(String),
+}
+impl From<parity_scale_codec::Error> for CoreError {
+	fn from(value: parity_scale_codec::Error) -> Self {
+		CoreError::CodecError(value)
+	}
+}
+impl From<hex::FromHexError> for CoreError {
+	fn from(value: hex::FromHexError) -> Self {
+		CoreError::FromHexError(value)
+	}
+}
+impl Display for CoreError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CoreError::FromHexError(e) => write!(f, "Cannot parse hex string: {e}"),
+			CoreError::ConversionError(msg) => write!(f, "{msg}"),
+			CoreError::CodecError(e) => write!(f, "Cannot decode: {e}"),
+			CoreError::InvalidEnvelope(msg) => write!(f, "Invalid envelope: {msg}"),
+		}
+	}
+}
+impl std::error::Error for CoreError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			CoreError::FromHexError(e) => Some(e),
+			_ => None,
+		}
+	}
 }
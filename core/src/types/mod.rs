@@ -1,7 +1,9 @@
 mod common;
 
+mod envelope;
 mod payload;
 mod payload_fields;
+mod signer_payload;
 mod transaction;
 
 pub mod avail;
@@ -9,6 +11,8 @@ pub mod error;
 pub mod multi;
 
 pub use common::{AlreadyEncoded, H256};
+pub use envelope::*;
 pub use payload::*;
 pub use payload_fields::*;
+pub use signer_payload::*;
 pub use transaction::*;
@@ -1,8 +1,8 @@
-use crate::crypto::AccountId;
-use parity_scale_codec::{Compact, Encode};
+use crate::crypto::{ecdsa, ed25519, sr25519, AccountId, Signature};
+use parity_scale_codec::{Compact, Decode, Encode};
 
 #[repr(u8)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MultiAddress {
 	/// It's an account ID (pubkey).
 	Id(AccountId) = 0,
@@ -48,8 +48,33 @@ impl Encode for MultiAddress {
 	}
 }
 
+impl From<AccountId> for MultiAddress {
+	fn from(value: AccountId) -> Self {
+		MultiAddress::Id(value)
+	}
+}
+
+impl Decode for MultiAddress {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let index: u8 = u8::decode(input)?;
+
+		match index {
+			0 => Ok(MultiAddress::Id(AccountId::decode(input)?)),
+			1 => Ok(MultiAddress::Index(Compact::<u32>::decode(input)?)),
+			2 => Ok(MultiAddress::Raw(Vec::<u8>::decode(input)?)),
+			3 => Ok(MultiAddress::Address32(<[u8; 32]>::decode(input)?)),
+			4 => Ok(MultiAddress::Address20(<[u8; 20]>::decode(input)?)),
+			_ => Err(parity_scale_codec::Error::from(
+				"Unknown MultiAddress Index",
+			)),
+		}
+	}
+}
+
 #[repr(u8)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MultiSignature {
 	/// An Ed25519 signature.
 	Ed25519([u8; 64]) = 0,
@@ -84,3 +109,94 @@ impl Encode for MultiSignature {
 		}
 	}
 }
+
+impl MultiSignature {
+	pub fn kind(&self) -> SignatureKind {
+		match self {
+			MultiSignature::Ed25519(_) => SignatureKind::Ed25519,
+			MultiSignature::Sr25519(_) => SignatureKind::Sr25519,
+			MultiSignature::Ecdsa(_) => SignatureKind::Ecdsa,
+		}
+	}
+
+	/// Checks that `signer` signed `message`, the same way the runtime does. For ecdsa the
+	/// account id is the blake2_256 hash of the recovered public key.
+	pub fn verify(&self, message: &[u8], signer: &AccountId) -> bool {
+		match self {
+			MultiSignature::Ed25519(x) => ed25519::Keypair::verify(
+				&ed25519::Signature(*x),
+				message,
+				ed25519::PublicKey(signer.0),
+			),
+			MultiSignature::Sr25519(x) => sr25519::Keypair::verify(
+				&sr25519::Signature(*x),
+				message,
+				sr25519::PublicKey(signer.0),
+			),
+			MultiSignature::Ecdsa(x) => ecdsa::Keypair::recover(&ecdsa::Signature(*x), message)
+				.is_some_and(|x| x.to_account_id() == *signer),
+		}
+	}
+}
+
+impl From<Signature> for MultiSignature {
+	fn from(value: Signature) -> Self {
+		MultiSignature::Sr25519(value.0)
+	}
+}
+
+impl Decode for MultiSignature {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let index: u8 = u8::decode(input)?;
+
+		match index {
+			0 => Ok(MultiSignature::Ed25519(<[u8; 64]>::decode(input)?)),
+			1 => Ok(MultiSignature::Sr25519(<[u8; 64]>::decode(input)?)),
+			2 => Ok(MultiSignature::Ecdsa(<[u8; 65]>::decode(input)?)),
+			_ => Err(parity_scale_codec::Error::from(
+				"Unknown MultiSignature Index",
+			)),
+		}
+	}
+}
+
+/// Signature scheme of a [`MultiSignature`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureKind {
+	Ed25519,
+	Sr25519,
+	Ecdsa,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::DecodeAll;
+
+	#[test]
+	fn multi_signature_round_trip() {
+		let signatures = [
+			MultiSignature::Ed25519([1u8; 64]),
+			MultiSignature::Sr25519([2u8; 64]),
+			MultiSignature::Ecdsa([3u8; 65]),
+		];
+		for (index, signature) in signatures.into_iter().enumerate() {
+			let encoded = signature.encode();
+			assert_eq!(encoded[0], index as u8);
+			assert_eq!(encoded.len(), signature.size_hint());
+			assert_eq!(
+				MultiSignature::decode_all(&mut encoded.as_slice()).unwrap(),
+				signature
+			);
+		}
+	}
+
+	#[test]
+	fn multi_signature_unknown_index() {
+		let mut encoded = MultiSignature::Sr25519([0u8; 64]).encode();
+		encoded[0] = 3;
+		assert!(MultiSignature::decode_all(&mut encoded.as_slice()).is_err());
+	}
+}
@@ -1,15 +1,17 @@
 use super::{
+	error::CoreError,
+	multi::MultiSignature,
 	payload_fields::{Additional, Call, Extra},
 	AlreadyEncoded,
 };
-use crate::crypto::{blake2_256, Keypair, Signature};
-use parity_scale_codec::Encode;
+use crate::crypto::{blake2_256, Signer};
+use parity_scale_codec::{Decode, Encode};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnsignedPayload {
-	call: Call,
-	extra: Extra,
-	additional: Additional,
+	pub call: Call,
+	pub extra: Extra,
+	pub additional: Additional,
 }
 impl UnsignedPayload {
 	pub fn new(call: Call, extra: Extra, additional: Additional) -> Self {
@@ -29,7 +31,7 @@ impl UnsignedPayload {
 	}
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnsignedEncodedPayload {
 	pub call: AlreadyEncoded,
 	pub extra: AlreadyEncoded,
@@ -44,7 +46,23 @@ impl UnsignedEncodedPayload {
 		}
 	}
 
-	pub fn sign(&self, signer: &Keypair) -> Signature {
+	/// Decodes the call, extra and additional parts back into their structured form.
+	pub fn decode(&self) -> Result<UnsignedPayload, CoreError> {
+		let call = Call::decode(&mut self.call.0.as_slice())?;
+		let extra = Extra::decode(&mut self.extra.0.as_slice())?;
+		let additional = Additional::decode(&mut self.additional.0.as_slice())?;
+
+		Ok(UnsignedPayload::new(call, extra, additional))
+	}
+
+	/// Signs the payload. See [`Signer::sign_payload`].
+	pub fn sign<S: Signer + ?Sized>(&self, signer: &S) -> MultiSignature {
+		signer.sign_payload(self)
+	}
+
+	/// Calls `f` with the bytes that have to be signed. Payloads longer than 256 bytes are
+	/// replaced by their blake2_256 hash.
+	pub fn using_signing_bytes<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
 		let mut bytes: Vec<u8> = Vec::with_capacity(
 			self.call.size_hint() + self.extra.size_hint() + self.additional.size_hint(),
 		);
@@ -55,9 +73,9 @@ impl UnsignedEncodedPayload {
 
 		if bytes.len() > 256 {
 			let blake = blake2_256(&bytes);
-			signer.sign(blake.as_ref())
+			f(blake.as_ref())
 		} else {
-			signer.sign(&bytes)
+			f(&bytes)
 		}
 	}
 }
@@ -1,12 +1,13 @@
 use super::{AlreadyEncoded, H256};
-use parity_scale_codec::{Compact, Encode};
+use parity_scale_codec::{Compact, Decode, Encode};
+use serde::{Deserialize, Serialize};
 
 /// Era period
 pub type Period = u64;
 /// Era phase
 pub type Phase = u64;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Call {
 	pub pallet_index: u8,
 	pub call_index: u8,
@@ -32,8 +33,20 @@ impl Encode for Call {
 		self.fields.encode_to(dest);
 	}
 }
+// Call fields are not length prefixed so the call has to be the last thing in the input.
+impl Decode for Call {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let pallet_index = u8::decode(input)?;
+		let call_index = u8::decode(input)?;
+		let fields = AlreadyEncoded::decode(input)?;
 
-#[derive(Debug, Clone)]
+		Ok(Self::new(pallet_index, call_index, fields))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Extra {
 	pub mortality: Era,
 	pub nonce: Compact<u32>,
@@ -55,13 +68,30 @@ impl Encode for Extra {
 		self.app_id.encode_to(dest);
 	}
 }
+impl Decode for Extra {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let mortality = Era::decode(input)?;
+		let nonce = Compact::<u32>::decode(input)?;
+		let tip = Compact::<u128>::decode(input)?;
+		let app_id = Compact::<u32>::decode(input)?;
+
+		Ok(Self {
+			mortality,
+			nonce,
+			tip,
+			app_id,
+		})
+	}
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Additional {
-	spec_version: u32,
-	tx_version: u32,
-	genesis_hash: H256,
-	fork_hash: H256,
+	pub spec_version: u32,
+	pub tx_version: u32,
+	pub genesis_hash: H256,
+	pub fork_hash: H256,
 }
 
 impl Additional {
@@ -91,7 +121,20 @@ impl Encode for Additional {
 	}
 }
 
-#[derive(Debug, Clone)]
+impl Decode for Additional {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let spec_version = u32::decode(input)?;
+		let tx_version = u32::decode(input)?;
+		let genesis_hash = H256::decode(input)?;
+		let fork_hash = H256::decode(input)?;
+
+		Ok(Self::new(spec_version, tx_version, genesis_hash, fork_hash))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Era {
 	Immortal,
 	Mortal(Period, Phase),
@@ -119,6 +162,23 @@ impl Era {
 	pub fn immortal() -> Self {
 		Self::Immortal
 	}
+
+	/// Get the block number of the start of the era whose properties this object describes that
+	/// `current` belongs to.
+	pub fn birth(&self, current: u64) -> u64 {
+		match self {
+			Self::Immortal => 0,
+			Self::Mortal(period, phase) => (current.max(*phase) - phase) / period * period + phase,
+		}
+	}
+
+	/// Get the block number of the first block at which the era has ended.
+	pub fn death(&self, current: u64) -> u64 {
+		match self {
+			Self::Immortal => u64::MAX,
+			Self::Mortal(period, _) => self.birth(current).saturating_add(*period),
+		}
+	}
 }
 impl Encode for Era {
 	fn size_hint(&self) -> usize {
@@ -133,10 +193,107 @@ impl Encode for Era {
 			Self::Immortal => dest.push_byte(0),
 			Self::Mortal(period, phase) => {
 				let quantize_factor = (*period >> 12).max(1);
-				let encoded = (period.trailing_zeros() - 1).clamp(1, 15) as u16
+				let encoded = period.trailing_zeros().saturating_sub(1).clamp(1, 15) as u16
 					| ((phase / quantize_factor) << 4) as u16;
 				encoded.encode_to(dest);
 			},
 		}
 	}
 }
+impl Decode for Era {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let first = input.read_byte()?;
+		if first == 0 {
+			return Ok(Self::Immortal);
+		}
+
+		let encoded = first as u64 + ((input.read_byte()? as u64) << 8);
+		let period = 2 << (encoded % (1 << 4));
+		let quantize_factor = (period >> 12).max(1);
+		let phase = (encoded >> 4) * quantize_factor;
+		if period >= 4 && phase < period {
+			Ok(Self::Mortal(period, phase))
+		} else {
+			Err(parity_scale_codec::Error::from("Invalid period and phase"))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::DecodeAll;
+
+	#[test]
+	fn immortal_era_codec() {
+		assert_eq!(Era::immortal().encode(), vec![0u8]);
+		assert_eq!(
+			Era::decode_all(&mut [0u8].as_slice()).unwrap(),
+			Era::Immortal
+		);
+	}
+
+	// Vectors from `sp_runtime::generic::Era`.
+	#[test]
+	fn mortal_era_codec() {
+		let era = Era::mortal(64, 42);
+		assert_eq!(era, Era::Mortal(64, 42));
+		assert_eq!(
+			era.encode(),
+			vec![(5 + 42 % 16 * 16) as u8, (42 / 16) as u8]
+		);
+		assert_eq!(Era::decode_all(&mut era.encode().as_slice()).unwrap(), era);
+
+		let era = Era::mortal(32768, 20_000);
+		assert_eq!(era, Era::Mortal(32768, 20_000));
+		assert_eq!(
+			era.encode(),
+			vec![(14 + 2500 % 16 * 16) as u8, (2500 / 16) as u8]
+		);
+		assert_eq!(Era::decode_all(&mut era.encode().as_slice()).unwrap(), era);
+	}
+
+	#[test]
+	fn era_birth_and_death() {
+		let era = Era::mortal(8, 10);
+		assert_eq!(era.birth(10), 10);
+		assert_eq!(era.birth(17), 10);
+		assert_eq!(era.birth(18), 18);
+		assert_eq!(era.death(10), 18);
+		assert_eq!(era.death(17), 18);
+
+		// Phase 40 of a 64 block era, checkpointed at 1_000.
+		let era = Era::mortal(64, 1_000);
+		assert_eq!(era, Era::Mortal(64, 40));
+		assert_eq!(era.birth(1_000), 1_000);
+		assert_eq!(era.birth(1_063), 1_000);
+		assert_eq!(era.death(1_000), 1_064);
+		assert_eq!(era.birth(1_064), 1_064);
+		// Blocks before the phase belong to the first era.
+		assert_eq!(era.birth(0), 40);
+	}
+
+	#[test]
+	fn immortal_era_birth_and_death() {
+		let era = Era::immortal();
+		assert_eq!(era.birth(0), 0);
+		assert_eq!(era.birth(1_000_000), 0);
+		assert_eq!(era.death(0), u64::MAX);
+		assert_eq!(era.death(u64::MAX), u64::MAX);
+	}
+
+	#[test]
+	fn extra_round_trip() {
+		let extra = Extra {
+			mortality: Era::mortal(32, 1_000),
+			nonce: Compact(u32::MAX),
+			tip: Compact(u128::MAX),
+			app_id: Compact(0),
+		};
+		let encoded = extra.encode();
+		assert_eq!(Extra::decode_all(&mut encoded.as_slice()).unwrap(), extra);
+		assert!(Extra::decode_all(&mut &encoded[..encoded.len() - 1]).is_err());
+	}
+}
@@ -0,0 +1,247 @@
+use super::{
+	error::CoreError,
+	multi::MultiSignature,
+	payload_fields::{Additional, Call, Era, Extra},
+	AlreadyEncoded, OpaqueTransaction, UnsignedEncodedPayload, UnsignedPayload, H256,
+	TRANSACTION_VERSION,
+};
+use crate::crypto::{AccountId, Ss58Codec};
+use parity_scale_codec::{Compact, DecodeAll, Encode};
+use serde::{Deserialize, Serialize};
+
+/// Signed extensions of the Avail runtime, in the order they appear in the extra.
+pub const SIGNED_EXTENSIONS: [&str; 9] = [
+	"CheckNonZeroSender",
+	"CheckSpecVersion",
+	"CheckTxVersion",
+	"CheckGenesis",
+	"CheckMortality",
+	"CheckNonce",
+	"CheckWeight",
+	"ChargeTransactionPayment",
+	"CheckAppId",
+];
+
+/// The `SignerPayloadJSON` structure handed to `signer.signPayload` of a polkadot-js extension.
+///
+/// Numbers are hex encoded the same way polkadot-js does it, e.g. `nonce` is `0x00000001`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerPayloadJson {
+	/// SS58 address of the signer.
+	pub address: String,
+	pub app_id: String,
+	pub block_hash: String,
+	pub block_number: String,
+	pub era: String,
+	pub genesis_hash: String,
+	/// The encoded call.
+	pub method: String,
+	pub nonce: String,
+	pub signed_extensions: Vec<String>,
+	pub spec_version: String,
+	pub tip: String,
+	pub transaction_version: String,
+	pub version: u8,
+}
+impl SignerPayloadJson {
+	/// `block_number` is the number of the block the era is checkpointed at. It is only shown by
+	/// the extension since the era is already part of the payload.
+	pub fn new(payload: &UnsignedPayload, address: &AccountId, block_number: u32) -> Self {
+		let UnsignedPayload {
+			call,
+			extra,
+			additional,
+		} = payload;
+
+		Self {
+			address: address.to_ss58check(),
+			app_id: std::format!("0x{:08x}", extra.app_id.0),
+			block_hash: additional.fork_hash.to_hex_string(),
+			block_number: std::format!("0x{:08x}", block_number),
+			era: to_hex(&extra.mortality.encode()),
+			genesis_hash: additional.genesis_hash.to_hex_string(),
+			method: to_hex(&call.encode()),
+			nonce: std::format!("0x{:08x}", extra.nonce.0),
+			signed_extensions: SIGNED_EXTENSIONS.iter().map(|x| x.to_string()).collect(),
+			spec_version: std::format!("0x{:08x}", additional.spec_version),
+			tip: std::format!("0x{:032x}", extra.tip.0),
+			transaction_version: std::format!("0x{:08x}", additional.tx_version),
+			version: TRANSACTION_VERSION,
+		}
+	}
+
+	pub fn from_encoded(
+		payload: &UnsignedEncodedPayload,
+		address: &AccountId,
+		block_number: u32,
+	) -> Result<Self, CoreError> {
+		Ok(Self::new(&payload.decode()?, address, block_number))
+	}
+
+	/// Fails if `version` or `signed_extensions` differ from what this crate encodes, since the
+	/// resulting transaction would not match what was signed.
+	pub fn to_unsigned_payload(&self) -> Result<UnsignedPayload, CoreError> {
+		if self.version != TRANSACTION_VERSION {
+			let msg = std::format!("Unsupported transaction version {}", self.version);
+			return Err(CoreError::ConversionError(msg));
+		}
+		if self.signed_extensions != SIGNED_EXTENSIONS {
+			let msg = std::format!("Unsupported signed extensions {:?}", self.signed_extensions);
+			return Err(CoreError::ConversionError(msg));
+		}
+
+		let call = Call::decode_all(&mut from_hex(&self.method)?.as_slice())?;
+		let extra = Extra {
+			mortality: Era::decode_all(&mut from_hex(&self.era)?.as_slice())?,
+			nonce: Compact(number_from_hex(&self.nonce)?),
+			tip: Compact(number_from_hex(&self.tip)?),
+			app_id: Compact(number_from_hex(&self.app_id)?),
+		};
+		let additional = Additional::new(
+			number_from_hex(&self.spec_version)?,
+			number_from_hex(&self.transaction_version)?,
+			H256::from_hex_string(&self.genesis_hash)?,
+			H256::from_hex_string(&self.block_hash)?,
+		);
+
+		Ok(UnsignedPayload::new(call, extra, additional))
+	}
+
+	pub fn to_encoded_payload(&self) -> Result<UnsignedEncodedPayload, CoreError> {
+		Ok(self.to_unsigned_payload()?.encode())
+	}
+
+	/// Joins the signature returned by the extension into a transaction. `signature` is the hex
+	/// string from the extension's `SignerResult`, which is prefixed by the signature type.
+	pub fn into_transaction(&self, signature: &str) -> Result<OpaqueTransaction, CoreError> {
+		let signature = MultiSignature::decode_all(&mut from_hex(signature)?.as_slice())?;
+		let account_id = account_id_from_ss58(&self.address)?;
+		let payload = self.to_encoded_payload()?;
+
+		Ok(OpaqueTransaction::new(
+			&payload.extra,
+			&payload.call,
+			account_id.into(),
+			signature,
+		))
+	}
+}
+
+/// The `SignerPayloadRaw` structure handed to `signer.signRaw` of a polkadot-js extension.
+///
+/// `data` holds the call, extra and additional concatenated together. The call is not length
+/// prefixed, so the raw form cannot be split back into its parts. Use [`SignerPayloadJson`]
+/// when the payload has to be reconstructed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerPayloadRaw {
+	/// SS58 address of the signer.
+	pub address: String,
+	pub data: String,
+	/// Either `bytes` or `payload`. Payloads built by this crate are always `payload`.
+	#[serde(rename = "type")]
+	pub kind: String,
+}
+impl SignerPayloadRaw {
+	pub fn new(payload: &UnsignedEncodedPayload, address: &AccountId) -> Self {
+		let mut data = Vec::new();
+		payload.call.encode_to(&mut data);
+		payload.extra.encode_to(&mut data);
+		payload.additional.encode_to(&mut data);
+
+		Self {
+			address: address.to_ss58check(),
+			data: to_hex(&data),
+			kind: "payload".to_string(),
+		}
+	}
+
+	/// Joins a signature over `data` into a transaction. Fails if `payload` is not the one this
+	/// raw payload was created from.
+	///
+	/// polkadot-js extensions sign `<Bytes>data</Bytes>` in `signRaw`, so their signatures do not
+	/// verify as transaction signatures. `signature` has to be over `data` itself, e.g. from a
+	/// signer that does not wrap the message.
+	pub fn into_transaction(
+		&self,
+		payload: &UnsignedEncodedPayload,
+		signature: MultiSignature,
+	) -> Result<OpaqueTransaction, CoreError> {
+		let account_id = account_id_from_ss58(&self.address)?;
+		if Self::new(payload, &account_id).data != self.data {
+			let msg = "Raw payload data does not match the given payload".to_string();
+			return Err(CoreError::ConversionError(msg));
+		}
+
+		Ok(OpaqueTransaction::new(
+			&payload.extra,
+			&payload.call,
+			account_id.into(),
+			signature,
+		))
+	}
+}
+
+fn to_hex(value: &[u8]) -> String {
+	AlreadyEncoded(value.to_vec()).to_hex_string()
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, CoreError> {
+	hex::decode(s.trim_start_matches("0x")).map_err(CoreError::FromHexError)
+}
+
+fn number_from_hex<T: TryFrom<u128>>(s: &str) -> Result<T, CoreError> {
+	let msg = || std::format!("Failed to convert {s} to a number");
+	let value = u128::from_str_radix(s.trim_start_matches("0x"), 16)
+		.map_err(|_| CoreError::ConversionError(msg()))?;
+
+	T::try_from(value).map_err(|_| CoreError::ConversionError(msg()))
+}
+
+fn account_id_from_ss58(s: &str) -> Result<AccountId, CoreError> {
+	AccountId::from_ss58check(s)
+		.map_err(|e| CoreError::ConversionError(std::format!("Invalid address {s}: {e:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::avail::DataAvailabilityCalls;
+
+	fn payload() -> UnsignedPayload {
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra {
+			mortality: Era::mortal(32, 1_000),
+			nonce: Compact(7),
+			tip: Compact(5),
+			app_id: Compact(3),
+		};
+		let additional = Additional::new(39, 1, H256([1u8; 32]), H256([2u8; 32]));
+
+		UnsignedPayload::new(call, extra, additional)
+	}
+
+	#[test]
+	fn json_round_trip() {
+		let payload = payload();
+		let json = SignerPayloadJson::new(&payload, &AccountId([3u8; 32]), 1_000);
+		assert_eq!(json.to_encoded_payload().unwrap(), payload.encode());
+	}
+
+	#[test]
+	fn json_with_other_extensions_is_rejected() {
+		let json = SignerPayloadJson::new(&payload(), &AccountId([3u8; 32]), 1_000);
+
+		let mut other = json.clone();
+		other.signed_extensions.pop();
+		assert!(other.to_unsigned_payload().is_err());
+
+		let mut other = json.clone();
+		other.signed_extensions.swap(0, 1);
+		assert!(other.to_unsigned_payload().is_err());
+
+		let mut other = json;
+		other.version = 5;
+		assert!(other.to_unsigned_payload().is_err());
+	}
+}
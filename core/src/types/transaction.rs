@@ -1,11 +1,18 @@
 use super::{
+	error::CoreError,
 	multi::{MultiAddress, MultiSignature},
-	AlreadyEncoded, H256,
+	payload_fields::{Call, Extra},
+	AlreadyEncoded, UnsignedEncodedPayload, H256,
 };
-use crate::crypto::{blake2_256, AccountId, Signature};
-use parity_scale_codec::{Compact, Encode};
+use crate::crypto::{blake2_256, sign_checked, Signer};
+use parity_scale_codec::{Compact, Decode, Encode};
 
-#[derive(Debug, Clone)]
+/// Transaction protocol version supported by the Avail runtime.
+pub const TRANSACTION_VERSION: u8 = 4;
+/// Bit of the version byte which marks a transaction as signed.
+const SIGNED_FLAG: u8 = 0b1000_0000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OpaqueTransaction {
 	pub data: AlreadyEncoded,
 }
@@ -13,19 +20,19 @@ impl OpaqueTransaction {
 	pub fn new(
 		payload_extra: &AlreadyEncoded,
 		payload_call: &AlreadyEncoded,
-		account_id: AccountId,
-		signature: Signature,
+		address: MultiAddress,
+		signature: MultiSignature,
 	) -> Self {
 		let mut encoded_inner: Vec<u8> = Vec::new();
 
 		// "is signed" + transaction protocol version (4)
-		(0b10000000 + 4u8).encode_to(&mut encoded_inner);
+		(SIGNED_FLAG + TRANSACTION_VERSION).encode_to(&mut encoded_inner);
 
 		// Attach Address from Signer
-		MultiAddress::Id(account_id).encode_to(&mut encoded_inner);
+		address.encode_to(&mut encoded_inner);
 
 		// Attach Signature
-		MultiSignature::Sr25519(signature.0).encode_to(&mut encoded_inner);
+		signature.encode_to(&mut encoded_inner);
 
 		// Attach Extra
 		payload_extra.encode_to(&mut encoded_inner);
@@ -44,10 +51,40 @@ impl OpaqueTransaction {
 		Self::new_raw(AlreadyEncoded(encoded))
 	}
 
+	/// Signs `payload` with `signer` and builds the signed transaction. Fails if the signature
+	/// does not match [`Signer::signature_kind`].
+	pub fn from_signer<S: Signer + ?Sized>(
+		payload: &UnsignedEncodedPayload,
+		signer: &S,
+	) -> Result<Self, CoreError> {
+		let signature = sign_checked(signer, payload)?;
+		Ok(Self::new(
+			&payload.extra,
+			&payload.call,
+			signer.address(),
+			signature,
+		))
+	}
+
 	pub fn new_raw(data: AlreadyEncoded) -> Self {
 		Self { data }
 	}
 
+	/// Parses a hex encoded transaction, as returned by `chain_getBlock` or `to_hex_string`.
+	pub fn from_hex_string(s: &str) -> Result<Self, CoreError> {
+		let s = s.trim_start_matches("0x");
+		let bytes = hex::decode(s).map_err(CoreError::FromHexError)?;
+
+		let mut input = bytes.as_slice();
+		let transaction = Self::decode(&mut input)?;
+		if !input.is_empty() {
+			let msg = std::format!("Found {} trailing bytes after transaction", input.len());
+			return Err(CoreError::ConversionError(msg));
+		}
+
+		Ok(transaction)
+	}
+
 	pub fn to_hex_string(&self) -> String {
 		self.data.to_hex_string()
 	}
@@ -55,4 +92,215 @@ impl OpaqueTransaction {
 	pub fn get_hash(&self) -> H256 {
 		H256(blake2_256(&self.data.0))
 	}
+
+	/// Decodes the transaction into its signer, signature, extra and call.
+	pub fn decoded(&self) -> Result<DecodedTransaction, CoreError> {
+		DecodedTransaction::decode(&mut self.data.0.as_slice()).map_err(CoreError::from)
+	}
+}
+impl Encode for OpaqueTransaction {
+	fn size_hint(&self) -> usize {
+		self.data.0.len()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.data.encode_to(dest);
+	}
+}
+// The inner data is already length prefixed. We only read as much as the prefix says and keep
+// the prefix as part of the data.
+impl Decode for OpaqueTransaction {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let inner = Vec::<u8>::decode(input)?;
+		Ok(Self::new_raw(AlreadyEncoded(inner.encode())))
+	}
+}
+
+/// Signer related part of a signed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionSignature {
+	pub address: MultiAddress,
+	pub signature: MultiSignature,
+	pub extra: Extra,
+}
+
+/// Structured view of an [`OpaqueTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedTransaction {
+	/// `None` for unsigned transactions and inherents.
+	pub signature: Option<TransactionSignature>,
+	pub call: Call,
+}
+impl DecodedTransaction {
+	pub fn is_signed(&self) -> bool {
+		self.signature.is_some()
+	}
+}
+impl Encode for DecodedTransaction {
+	fn size_hint(&self) -> usize {
+		let signature = match &self.signature {
+			Some(x) => x.address.size_hint() + x.signature.size_hint() + x.extra.size_hint(),
+			None => 0,
+		};
+
+		// Length prefix + version + signature + call
+		5 + 1 + signature + self.call.size_hint()
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		let mut encoded_inner: Vec<u8> = Vec::new();
+		match &self.signature {
+			Some(x) => {
+				(SIGNED_FLAG + TRANSACTION_VERSION).encode_to(&mut encoded_inner);
+				x.address.encode_to(&mut encoded_inner);
+				x.signature.encode_to(&mut encoded_inner);
+				x.extra.encode_to(&mut encoded_inner);
+			},
+			None => TRANSACTION_VERSION.encode_to(&mut encoded_inner),
+		}
+		self.call.encode_to(&mut encoded_inner);
+
+		// Same as Vec<u8>: compact length followed by the bytes
+		encoded_inner.encode_to(dest);
+	}
+}
+impl Decode for DecodedTransaction {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let inner = Vec::<u8>::decode(input)?;
+		let mut inner = inner.as_slice();
+
+		let version = u8::decode(&mut inner)?;
+		if version & !SIGNED_FLAG != TRANSACTION_VERSION {
+			return Err(parity_scale_codec::Error::from(
+				"Unsupported transaction protocol version",
+			));
+		}
+
+		let signature = if version & SIGNED_FLAG != 0 {
+			Some(TransactionSignature {
+				address: MultiAddress::decode(&mut inner)?,
+				signature: MultiSignature::decode(&mut inner)?,
+				extra: Extra::decode(&mut inner)?,
+			})
+		} else {
+			None
+		};
+		let call = Call::decode(&mut inner)?;
+
+		Ok(Self { signature, call })
+	}
+}
+impl From<&DecodedTransaction> for OpaqueTransaction {
+	fn from(value: &DecodedTransaction) -> Self {
+		Self::new_raw(AlreadyEncoded(value.encode()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		crypto::{ecdsa, ed25519, AccountId, Keypair, SecretUri},
+		types::{
+			avail::DataAvailabilityCalls, multi::SignatureKind, Additional, Era, UnsignedPayload,
+		},
+	};
+	use std::str::FromStr;
+
+	fn payload(mortality: Era) -> UnsignedEncodedPayload {
+		let call = DataAvailabilityCalls::submit_data(vec![1, 2, 3]);
+		let extra = Extra {
+			mortality,
+			nonce: Compact(7),
+			tip: Compact(1_000),
+			app_id: Compact(3),
+		};
+		let additional = Additional::new(39, 1, H256([1u8; 32]), H256([2u8; 32]));
+
+		UnsignedPayload::new(call, extra, additional).encode()
+	}
+
+	fn assert_round_trip<S: Signer>(signer: &S, mortality: Era) {
+		let payload = payload(mortality);
+		let transaction = OpaqueTransaction::from_signer(&payload, signer).unwrap();
+
+		let decoded = transaction.decoded().unwrap();
+		let signature = decoded.signature.clone().unwrap();
+		assert_eq!(signature.address, signer.address());
+		assert_eq!(signature.signature.kind(), signer.signature_kind());
+		assert_eq!(signature.extra.encode(), payload.extra.0);
+		assert_eq!(decoded.call.encode(), payload.call.0);
+
+		let rebuilt = OpaqueTransaction::new(
+			&AlreadyEncoded(signature.extra.encode()),
+			&AlreadyEncoded(decoded.call.encode()),
+			signature.address,
+			signature.signature,
+		);
+		assert_eq!(rebuilt, transaction);
+		assert_eq!(OpaqueTransaction::from(&decoded), transaction);
+
+		let parsed = OpaqueTransaction::from_hex_string(&transaction.to_hex_string()).unwrap();
+		assert_eq!(parsed, transaction);
+	}
+
+	/// Announces sr25519 but signs with ed25519.
+	struct Mislabeled(ed25519::Keypair);
+	impl Signer for Mislabeled {
+		fn account_id(&self) -> AccountId {
+			self.0.account_id()
+		}
+
+		fn signature_kind(&self) -> SignatureKind {
+			SignatureKind::Sr25519
+		}
+
+		fn sign(&self, message: &[u8]) -> MultiSignature {
+			Signer::sign(&self.0, message)
+		}
+	}
+
+	fn uri() -> SecretUri {
+		SecretUri::from_str("//Alice").unwrap()
+	}
+
+	#[test]
+	fn sr25519_round_trip() {
+		let signer = Keypair::from_uri(&uri()).unwrap();
+		assert_round_trip(&signer, Era::mortal(32, 1_000));
+		assert_round_trip(&signer, Era::immortal());
+	}
+
+	#[test]
+	fn ed25519_round_trip() {
+		let signer = ed25519::Keypair::from_uri(&uri()).unwrap();
+		assert_round_trip(&signer, Era::mortal(64, 12_345));
+		assert_round_trip(&signer, Era::immortal());
+	}
+
+	#[test]
+	fn ecdsa_round_trip() {
+		let signer = ecdsa::Keypair::from_uri(&uri()).unwrap();
+		assert_round_trip(&signer, Era::mortal(4, 3));
+		assert_round_trip(&signer, Era::immortal());
+	}
+
+	#[test]
+	fn trailing_bytes_are_rejected() {
+		let signer = Keypair::from_uri(&uri()).unwrap();
+		let transaction =
+			OpaqueTransaction::from_signer(&payload(Era::immortal()), &signer).unwrap();
+		let hex = std::format!("{}00", transaction.to_hex_string());
+		assert!(OpaqueTransaction::from_hex_string(&hex).is_err());
+	}
+
+	#[test]
+	fn mismatching_signature_kind_is_rejected() {
+		let signer = Mislabeled(ed25519::Keypair::from_uri(&uri()).unwrap());
+		assert!(OpaqueTransaction::from_signer(&payload(Era::immortal()), &signer).is_err());
+	}
 }
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parity_scale_codec::Decode;
+use sdk_core::types::avail::block::{Digest, DigestItem};
+
+fuzz_target!(|data: &[u8]| {
+	if let Ok(item) = DigestItem::decode(&mut &data[..]) {
+		let _ = item.to_human_readable();
+	}
+
+	if let Ok(digest) = serde_json::from_slice::<Digest>(data) {
+		let _ = digest.to_human_readable();
+	}
+});
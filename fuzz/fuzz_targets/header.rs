@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sdk_core::types::avail::block::{Header, SignedBlock};
+
+fuzz_target!(|data: &[u8]| {
+	if let Ok(header) = serde_json::from_slice::<Header>(data) {
+		let _ = header.digest.to_human_readable();
+	}
+
+	if let Ok(block) = serde_json::from_slice::<SignedBlock>(data) {
+		let _ = block.block.decoded_extrinsics();
+	}
+});
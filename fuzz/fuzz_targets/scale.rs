@@ -0,0 +1,43 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parity_scale_codec::{Decode, Encode};
+use sdk_core::types::{
+	avail::{
+		calls::DecodedCall,
+		dispatch::ApplyExtrinsicResult,
+		transaction_payment::{FeeDetails, RuntimeDispatchInfo},
+		transaction_validity::TransactionValidity,
+	},
+	multi::{MultiAddress, MultiSignature},
+	Additional, Call, DecodedTransaction, Era, Extra, OpaqueTransaction, PayloadEnvelope,
+	SignatureEnvelope,
+};
+
+// Decodes `data` as `T` and encodes it again, so both directions are covered.
+fn round_trip<T: Decode + Encode>(data: &[u8]) {
+	if let Ok(value) = T::decode(&mut &data[..]) {
+		let _ = value.encode();
+	}
+}
+
+fuzz_target!(|data: &[u8]| {
+	round_trip::<OpaqueTransaction>(data);
+	round_trip::<DecodedTransaction>(data);
+	round_trip::<Era>(data);
+	round_trip::<Extra>(data);
+	round_trip::<Additional>(data);
+	round_trip::<MultiAddress>(data);
+	round_trip::<MultiSignature>(data);
+	round_trip::<PayloadEnvelope>(data);
+	round_trip::<SignatureEnvelope>(data);
+
+	if let Ok(call) = Call::decode(&mut &data[..]) {
+		let _ = DecodedCall::from_call(call).to_string();
+	}
+
+	let _ = ApplyExtrinsicResult::decode(&mut &data[..]);
+	let _ = TransactionValidity::decode(&mut &data[..]);
+	let _ = RuntimeDispatchInfo::decode(&mut &data[..]);
+	let _ = FeeDetails::decode(&mut &data[..]);
+});